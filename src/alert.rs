@@ -0,0 +1,325 @@
+use std::collections::HashMap;
+#[cfg(feature = "notifications")]
+use std::process::{Command, Stdio};
+use std::time::{Duration, SystemTime};
+use crate::i18n::{Text, fill};
+use crate::monitor::{Sample, disk::DiskMonitor};
+
+/// 单项资源的告警状态：连续 samples 次超过阈值时触发一次，
+/// 之后保持触发状态，直到回落到阈值以下才重新计数（滞回），避免持续高负载时反复通知
+pub struct Alert {
+    threshold: f64,
+    samples: u32,
+    consecutive: u32,  // 连续超过阈值的次数
+    firing: bool,
+}
+
+impl Alert {
+    pub fn new(threshold: f64, samples: u32) -> Self {
+        Self {
+            threshold,
+            samples: samples.max(1),
+            consecutive: 0,
+            firing: false,
+        }
+    }
+
+    /// 记录一次读数，返回本次是否应发出通知
+    pub fn update(&mut self, value: f64) -> bool {
+        if value <= self.threshold {
+            self.consecutive = 0;
+            self.firing = false;
+            return false;
+        }
+        self.consecutive = self.consecutive.saturating_add(1);
+        if !self.firing && self.consecutive >= self.samples {
+            self.firing = true;
+            return true;
+        }
+        false
+    }
+}
+
+// 单个挂载点的告警状态
+struct MountState {
+    above_since: SystemTime,  // 本轮开始超过阈值的采样时刻
+    firing: bool,
+}
+
+/// 各挂载点的磁盘使用率告警：超过阈值并持续 grace 时长后触发一次，
+/// 回落到阈值以下后重新计时；按挂载点分别记录，消失的挂载点（已卸载）丢弃其状态
+pub struct DiskAlert {
+    threshold: f64,
+    grace: Duration,
+    mounts: HashMap<String, MountState>,
+}
+
+impl DiskAlert {
+    pub fn new(threshold: f64, grace: Duration) -> Self {
+        Self { threshold, grace, mounts: HashMap::new() }
+    }
+
+    /// 记录一次采样，返回本次新触发告警的 (挂载点, 使用率)；读取失败的磁盘保持原状态
+    pub fn update(&mut self, sample: &Sample) -> Vec<(String, f64)> {
+        self.mounts.retain(|mount, _| sample.disks.iter().any(|disk| disk.mount_point == *mount));
+
+        let mut fired = Vec::new();
+        for disk in sample.disks.iter().filter(|disk| disk.error.is_none()) {
+            let usage = DiskMonitor::usage_percentage(disk.total_space, disk.used_space);
+            if usage <= self.threshold {
+                self.mounts.remove(&disk.mount_point);
+                continue;
+            }
+            let state = self.mounts
+                .entry(disk.mount_point.clone())
+                .or_insert(MountState { above_since: disk.timestamp, firing: false });
+            let elapsed = disk.timestamp.duration_since(state.above_since).unwrap_or_default();
+            if !state.firing && elapsed >= self.grace {
+                state.firing = true;
+                fired.push((disk.mount_point.clone(), usage));
+            }
+        }
+        fired
+    }
+
+    /// 当前处于触发状态的挂载点，按名称排序
+    pub fn firing(&self) -> Vec<&str> {
+        let mut mounts: Vec<&str> = self.mounts.iter()
+            .filter(|(_, state)| state.firing)
+            .map(|(mount, _)| mount.as_str())
+            .collect();
+        mounts.sort_unstable();
+        mounts
+    }
+}
+
+/// 监视 CPU、内存和各磁盘的使用率，持续超限时发送桌面通知（需启用 notifications 特性）
+pub struct Watcher {
+    cpu: Option<Alert>,
+    memory: Option<Alert>,
+    disk: Option<DiskAlert>,
+}
+
+impl Watcher {
+    pub fn new(cpu: Option<f64>, memory: Option<f64>, samples: u32) -> Self {
+        Self {
+            cpu: cpu.map(|threshold| Alert::new(threshold, samples)),
+            memory: memory.map(|threshold| Alert::new(threshold, samples)),
+            disk: None,
+        }
+    }
+
+    pub fn with_disk(mut self, threshold: Option<f64>, grace: Duration) -> Self {
+        self.disk = threshold.map(|threshold| DiskAlert::new(threshold, grace));
+        self
+    }
+
+    /// 返回本次新触发的告警消息；数据缺失时该项不计数
+    pub fn check(&mut self, sample: &Sample) -> Vec<String> {
+        let mut messages = Vec::new();
+        if let (Some(alert), Some(cpu)) = (&mut self.cpu, &sample.cpu) {
+            if alert.update(cpu.total_usage as f64) {
+                messages.push(fill(Text::CpuAlert, &[&format!("{:.0}%", cpu.total_usage)]));
+            }
+        }
+        if let (Some(alert), Some(mem)) = (&mut self.memory, &sample.memory) {
+            let usage = DiskMonitor::usage_percentage(mem.total, mem.used);
+            if alert.update(usage) {
+                messages.push(fill(Text::MemoryAlert, &[&format!("{:.0}%", usage)]));
+            }
+        }
+        if let Some(alert) = &mut self.disk {
+            for (mount, usage) in alert.update(sample) {
+                messages.push(fill(Text::DiskAlert, &[&mount, &format!("{:.0}%", usage)]));
+            }
+        }
+        messages
+    }
+
+    /// 处于触发状态的磁盘告警，供 TUI 状态栏常驻显示；没有时为 None
+    pub fn disk_status(&self) -> Option<String> {
+        let mounts = self.disk.as_ref()?.firing();
+        (!mounts.is_empty()).then(|| fill(Text::DiskAlertStatus, &[&mounts.join(", ")]))
+    }
+
+    pub fn notify(&mut self, sample: &Sample) {
+        for message in self.check(sample) {
+            send_notification(&message);
+        }
+    }
+}
+
+// 调用系统自带的通知工具，在后台线程回收进程，不阻塞主循环；工具不存在时静默忽略，不影响监控。
+// Windows 的 msg 只发给当前会话（SESSIONNAME，如 Console、RDP-Tcp#0），
+// 不使用会广播到本机所有会话的 *；无法确定会话时不发送
+#[cfg(feature = "notifications")]
+fn send_notification(message: &str) {
+    let mut command = if cfg!(target_os = "macos") {
+        let mut command = Command::new("osascript");
+        command.arg("-e").arg(format!(
+            "display notification \"{}\" with title \"litemon\"",
+            message.replace('\\', "\\\\").replace('"', "\\\"")
+        ));
+        command
+    } else if cfg!(windows) {
+        let Some(session) = std::env::var_os("SESSIONNAME").filter(|session| !session.is_empty()) else {
+            return;
+        };
+        let mut command = Command::new("msg");
+        command.arg(session).arg(format!("litemon: {}", message));
+        command
+    } else {
+        let mut command = Command::new("notify-send");
+        command.arg("litemon").arg(message);
+        command
+    };
+    let child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+    if let Ok(mut child) = child {
+        std::thread::spawn(move || child.wait());
+    }
+}
+
+// 未启用 notifications 时只维护告警状态，供 TUI 状态栏和 --once 使用，不发送桌面通知
+#[cfg(not(feature = "notifications"))]
+fn send_notification(_message: &str) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitor::disk::{DiskKind, DiskStats};
+    use std::time::UNIX_EPOCH;
+
+    // 依次输入读数，返回发出通知的位置
+    fn notifications(alert: &mut Alert, values: &[f64]) -> Vec<usize> {
+        values.iter().enumerate().filter(|(_, &value)| alert.update(value)).map(|(i, _)| i).collect()
+    }
+
+    #[test]
+    fn fires_after_consecutive_samples() {
+        let mut alert = Alert::new(90.0, 3);
+        assert_eq!(notifications(&mut alert, &[95.0, 95.0, 95.0, 95.0]), [2]);
+    }
+
+    #[test]
+    fn dip_resets_the_count() {
+        let mut alert = Alert::new(90.0, 3);
+        assert_eq!(notifications(&mut alert, &[95.0, 95.0, 80.0, 95.0, 95.0, 95.0]), [5]);
+    }
+
+    #[test]
+    fn threshold_itself_is_not_exceeded() {
+        let mut alert = Alert::new(90.0, 1);
+        assert!(notifications(&mut alert, &[90.0, 90.0]).is_empty());
+    }
+
+    #[test]
+    fn no_refire_until_it_drops() {
+        let mut alert = Alert::new(90.0, 2);
+        let rising_and_falling = [95.0, 99.0, 100.0, 97.0, 50.0, 95.0, 96.0, 97.0];
+        assert_eq!(notifications(&mut alert, &rising_and_falling), [1, 6]);
+    }
+
+    #[test]
+    fn zero_samples_behaves_as_one() {
+        let mut alert = Alert::new(50.0, 0);
+        assert_eq!(notifications(&mut alert, &[10.0, 60.0]), [1]);
+    }
+
+    fn disk(mount: &str, used_percent: u64, seconds: u64) -> DiskStats {
+        DiskStats {
+            name: "/dev/sda1".to_string(),
+            mount_point: mount.to_string(),
+            disk_type: DiskKind::Ssd,
+            file_system: "ext4".to_string(),
+            total_space: 100,
+            used_space: used_percent,
+            available_space: 100 - used_percent,
+            is_removable: false,
+            mount_options: Vec::new(),
+            error: None,
+            inodes: None,
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 0,
+            timestamp: UNIX_EPOCH + Duration::from_secs(seconds),
+        }
+    }
+
+    fn sample(disks: Vec<DiskStats>) -> Sample {
+        Sample { disks, ..Sample::empty() }
+    }
+
+    fn fired(alert: &mut DiskAlert, disks: Vec<DiskStats>) -> Vec<String> {
+        alert.update(&sample(disks)).into_iter().map(|(mount, _)| mount).collect()
+    }
+
+    #[test]
+    fn disk_fires_once_after_grace() {
+        let mut alert = DiskAlert::new(90.0, Duration::from_secs(60));
+        assert!(fired(&mut alert, vec![disk("/", 95, 0)]).is_empty());
+        assert!(fired(&mut alert, vec![disk("/", 95, 59)]).is_empty());
+        assert!(alert.firing().is_empty());
+        assert_eq!(fired(&mut alert, vec![disk("/", 95, 60)]), ["/"]);
+        assert!(fired(&mut alert, vec![disk("/", 96, 120)]).is_empty());
+        assert_eq!(alert.firing(), ["/"]);
+    }
+
+    #[test]
+    fn disk_drop_below_threshold_restarts_grace() {
+        let mut alert = DiskAlert::new(90.0, Duration::from_secs(60));
+        fired(&mut alert, vec![disk("/", 95, 0)]);
+        fired(&mut alert, vec![disk("/", 90, 30)]);
+        assert!(fired(&mut alert, vec![disk("/", 95, 40)]).is_empty());
+        assert!(fired(&mut alert, vec![disk("/", 95, 99)]).is_empty());
+        assert_eq!(fired(&mut alert, vec![disk("/", 95, 100)]), ["/"]);
+
+        // 回落后清除触发状态，再次超限需重新等待
+        fired(&mut alert, vec![disk("/", 50, 110)]);
+        assert!(alert.firing().is_empty());
+        assert!(fired(&mut alert, vec![disk("/", 95, 120)]).is_empty());
+    }
+
+    #[test]
+    fn disk_mounts_are_independent() {
+        let mut alert = DiskAlert::new(90.0, Duration::from_secs(10));
+        fired(&mut alert, vec![disk("/", 95, 0), disk("/home", 50, 0)]);
+        assert!(fired(&mut alert, vec![disk("/", 95, 5), disk("/home", 95, 5)]).is_empty());
+        assert_eq!(fired(&mut alert, vec![disk("/", 95, 10), disk("/home", 95, 10)]), ["/"]);
+        assert_eq!(fired(&mut alert, vec![disk("/", 95, 15), disk("/home", 95, 15)]), ["/home"]);
+        assert_eq!(alert.firing(), ["/", "/home"]);
+    }
+
+    #[test]
+    fn disk_unmount_clears_state() {
+        let mut alert = DiskAlert::new(90.0, Duration::ZERO);
+        assert_eq!(fired(&mut alert, vec![disk("/mnt/usb", 99, 0)]), ["/mnt/usb"]);
+        fired(&mut alert, vec![]);
+        assert!(alert.firing().is_empty());
+        // 重新挂载后视为新的磁盘，再次通知
+        assert_eq!(fired(&mut alert, vec![disk("/mnt/usb", 99, 10)]), ["/mnt/usb"]);
+    }
+
+    #[test]
+    fn disk_read_error_keeps_state() {
+        let mut alert = DiskAlert::new(90.0, Duration::from_secs(10));
+        fired(&mut alert, vec![disk("/nfs", 95, 0)]);
+        let mut failed = disk("/nfs", 0, 5);
+        failed.error = Some("timeout".to_string());
+        fired(&mut alert, vec![failed]);
+        assert_eq!(fired(&mut alert, vec![disk("/nfs", 95, 10)]), ["/nfs"]);
+    }
+
+    #[test]
+    fn watcher_disk_status() {
+        let mut watcher = Watcher::new(None, None, 1).with_disk(Some(90.0), Duration::ZERO);
+        assert_eq!(watcher.disk_status(), None);
+        assert_eq!(watcher.check(&sample(vec![disk("/", 95, 0)])).len(), 1);
+        assert!(watcher.disk_status().is_some_and(|status| status.contains('/')));
+        assert!(watcher.check(&sample(vec![disk("/", 95, 1)])).is_empty());
+        watcher.check(&sample(vec![disk("/", 10, 2)]));
+        assert_eq!(watcher.disk_status(), None);
+    }
+}
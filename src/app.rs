@@ -0,0 +1,705 @@
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use crate::{
+    alert::Watcher,
+    change::ChangeDetector,
+    cli::{Cli, OutputFormat, UnitStyle},
+    error::{LiteMonError, Result},
+    exec::ExecHook,
+    exporter::Exporter,
+    influx,
+    i18n::{self, Lang, Text, fill, tr},
+    monitor::{
+        Monitor, Sample,
+        disk::DiskMonitor,
+        memory::MemoryMonitor,
+        process::{ProcessMonitor, ProcessSort},
+    },
+    recorder::Recorder,
+    summary::Summary,
+    remote::{self, HostState, RemoteHost},
+    ui::{self, FlatOptions, Tui},
+};
+
+// 方向键连续滚动的最小间隔
+const SCROLL_RATE: Duration = Duration::from_millis(50);
+
+// TUI 轮询按键的最长等待时间，与采样间隔无关
+const INPUT_POLL: Duration = Duration::from_millis(50);
+
+// 收到 SIGINT/SIGTERM 时置位，各主循环检查后正常返回，保证终端恢复和输出收尾
+static INTERRUPTED: OnceLock<Arc<AtomicBool>> = OnceLock::new();
+
+/// 按命令行参数运行：多主机面板、无界面输出或 TUI，返回前恢复终端并输出统计
+pub fn run(cli: Cli) -> Result<()> {
+    i18n::set_lang(cli.lang.unwrap_or_else(Lang::from_env));
+    install_interrupt_handler()?;
+
+    // 多主机面板只拉取远程采样，不需要本地监控
+    if let Some(path) = &cli.hosts {
+        let interval = cli.interval;
+        let hosts: Vec<RemoteHost> = remote::load_hosts(path)?
+            .into_iter()
+            .map(|(name, addr)| RemoteHost::spawn(name, addr, interval))
+            .collect();
+        let mut tui = Tui::new()?
+            .with_cores_per_page(cli.cores_per_page)
+            .with_disk_display(cli.disk_display)
+            .with_theme(cli.theme.clone())
+            .with_units(cli.units)
+            .with_flat(cli.flat)
+            .with_net_verbose(cli.net_verbose)
+            .with_net_bits(cli.net_bits)
+            .with_net_max(cli.net_max)
+            .with_history(cli.history as usize)
+            .with_color(cli.use_color());
+        tui.init()?;
+        let result = run_dashboard(&mut tui, &hosts, interval);
+        tui.cleanup()?;
+        return result;
+    }
+
+    let monitor = if cli.no_gpu {
+        Monitor::without_gpu()
+    } else {
+        Monitor::new()
+    };
+    let mut monitor = monitor
+        .with_refresh_spec(cli.monitors.refresh_spec())
+        .with_gpu_interval(cli.gpu_interval)
+        .with_cpu_smoothing(cli.cpu_smooth)
+        .with_host_memory(cli.host_memory)
+        .with_disk_dedup(!cli.no_dedup)
+        .with_disk_sort(cli.sort_disks)
+        .with_disk_filter(cli.all_disks, cli.disk_exclude.clone())
+        .with_net_aggregate(cli.net_aggregate)
+        .with_net_window(cli.net_window as usize)
+        .with_net_filter(cli.net_include.clone(), cli.net_exclude.clone())
+        .with_process_grouping(cli.group_processes)
+        .with_entropy(cli.entropy)
+        .with_connections(cli.connections)
+        .with_processes(shows_processes(&cli))
+        .with_raid(cli.raid)
+        .with_health_weights(cli.health_weights);
+    if cli.require_gpu && !monitor.has_gpu() {
+        return Err(LiteMonError::NoGpuFound);
+    }
+    monitor.warmup(cli.warmup.unwrap_or(Monitor::MINIMUM_WARMUP));
+
+    if cli.debug_capabilities {
+        eprint!("{}", monitor.capabilities());
+        return Ok(());
+    }
+
+    if cli.sample_and_exit_json {
+        return run_sample_and_exit(&mut monitor, cli.json_pretty, cli.per_core);
+    }
+
+    let mut recorder = cli.log.as_deref().map(Recorder::open).transpose()?;
+
+    if cli.once {
+        return run_once(&cli, &mut monitor, recorder.as_mut());
+    }
+
+    let mut exec = match &cli.exec {
+        Some(command) => Some(ExecHook::spawn(command, cli.exec_restart)?),
+        None => None,
+    };
+
+    // 有限次数运行结束时输出最小/最大/平均值统计
+    let mut summary = (cli.count > 0 && !cli.quiet).then(Summary::new);
+
+    if let Some(addr) = &cli.serve {
+        let exporter = Exporter::serve(addr, cli.metrics_format)?;
+        run_headless(&cli, &mut monitor, recorder.as_mut(), exec.as_mut(), summary.as_mut(), |sample| {
+            exporter.update(sample);
+            Ok(())
+        })?;
+        return print_summary(summary.as_ref(), &cli, true);
+    }
+
+    if cli.oneline {
+        run_oneline(&cli, &mut monitor, recorder.as_mut(), exec.as_mut(), summary.as_mut())?;
+        return print_summary(summary.as_ref(), &cli, true);
+    }
+
+    if let Some(template) = &cli.format_string {
+        run_headless(&cli, &mut monitor, recorder.as_mut(), exec.as_mut(), summary.as_mut(), |sample| {
+            writeln!(std::io::stdout().lock(), "{}", template.render(sample))?;
+            Ok(())
+        })?;
+        return print_summary(summary.as_ref(), &cli, true);
+    }
+
+    match cli.format {
+        OutputFormat::Tui => {}
+        OutputFormat::Text => {
+            if !cli.quiet {
+                println!("{}", tr(Text::Banner));
+                println!("{}", tr(Text::QuitHint));
+            }
+            run_headless(&cli, &mut monitor, recorder.as_mut(), exec.as_mut(), summary.as_mut(), |sample| {
+                print_text(sample, &cli)
+            })?;
+            // 让 shell 回显的 ^C 之后的提示符从新行开始
+            if interrupted() {
+                println!();
+            }
+            return print_summary(summary.as_ref(), &cli, false);
+        }
+        OutputFormat::Json => {
+            run_headless(&cli, &mut monitor, recorder.as_mut(), exec.as_mut(), summary.as_mut(), |sample| {
+                print_json(sample, cli.json_pretty, cli.per_core)
+            })?;
+            return print_summary(summary.as_ref(), &cli, true);
+        }
+        OutputFormat::Influx => {
+            let host = monitor.host_name();
+            run_headless(&cli, &mut monitor, recorder.as_mut(), exec.as_mut(), summary.as_mut(), |sample| {
+                print_influx(sample, host.as_deref(), cli.per_core)
+            })?;
+            return print_summary(summary.as_ref(), &cli, true);
+        }
+    }
+
+    let mut tui = Tui::new()?
+        .with_cores_per_page(cli.cores_per_page)
+        .with_disk_display(cli.disk_display)
+        .with_theme(cli.theme.clone())
+        .with_units(cli.units)
+        .with_flat(cli.flat)
+        .with_net_verbose(cli.net_verbose)
+        .with_net_bits(cli.net_bits)
+        .with_net_max(cli.net_max)
+        .with_history(cli.history as usize)
+        .with_color(cli.use_color());
+
+    tui.init()?;
+    let result = run_tui(&cli, &mut monitor, &mut tui, recorder.as_mut(), exec.as_mut(), summary.as_mut());
+    tui.cleanup()?;
+    result?;
+    print_summary(summary.as_ref(), &cli, false)
+}
+
+fn run_tui(
+    cli: &Cli,
+    monitor: &mut Monitor,
+    tui: &mut Tui,
+    mut recorder: Option<&mut Recorder>,
+    mut exec: Option<&mut ExecHook>,
+    mut summary: Option<&mut Summary>,
+) -> Result<()> {
+    // 两个计时器：按键以 INPUT_POLL 为超时轮询，保证响应及时；
+    // 采样只在距上次刷新满 --interval 时进行，避免频繁调用 refresh_all。
+    // 轮询超时不超过距下次刷新的剩余时间，刷新也不会被推迟
+    let tick_rate = cli.interval;
+    let mut last_tick = Instant::now();
+    let mut last_scroll = Instant::now();
+    let mut redraw_needed = false;
+    let mut change_detector = cli.on_change.then(|| ChangeDetector::new(cli.change_delta));
+    let mut watcher = watcher(cli);
+
+    let mut sample = monitor.sample();
+    tui.draw(&sample)?;
+    if let Some(summary) = summary.as_deref_mut() {
+        summary.update(&sample);
+    }
+    emit(&sample, recorder.as_deref_mut(), exec.as_deref_mut(), change_detector.as_mut())?;
+    watcher.notify(&sample);
+    tui.set_alert(watcher.disk_status());
+    let mut cycles = 1;
+
+    while !interrupted() {
+        let now = Instant::now();
+
+        // 暂停时只重绘上一次的采样，不刷新也不计入 --count
+        if tui.is_paused() && now.duration_since(last_tick) >= tick_rate {
+            tui.draw(&sample)?;
+            last_tick = now;
+            redraw_needed = false;
+        } else if now.duration_since(last_tick) >= tick_rate {
+            // 最后一次画面保留一个周期后再退出
+            if cli.count > 0 && cycles >= cli.count {
+                break;
+            }
+            cycles += 1;
+            monitor.refresh();
+            sample = monitor.sample();
+            if let Some(limit) = cli.self_mem_limit {
+                tui.set_status(check_self_memory(monitor, limit.saturating_mul(1024 * 1024), cli.units));
+            }
+            tui.draw(&sample)?;
+            if let Some(summary) = summary.as_deref_mut() {
+                summary.update(&sample);
+            }
+            emit(&sample, recorder.as_deref_mut(), exec.as_deref_mut(), change_detector.as_mut())?;
+            watcher.notify(&sample);
+            tui.set_alert(watcher.disk_status());
+            last_tick = now;
+            redraw_needed = false;
+        }
+
+        let timeout = tick_rate.saturating_sub(last_tick.elapsed()).min(INPUT_POLL);
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                match key.code {
+                    _ if is_quit_key(&key) => break,
+                    KeyCode::Char(' ') => {
+                        tui.toggle_paused();
+                        if !tui.is_paused() {
+                            monitor.resume();
+                            last_tick = Instant::now();
+                        }
+                        redraw_needed = true;
+                    }
+                    _ => redraw_needed |= handle_view_key(tui, key, &mut last_scroll),
+                }
+            }
+        }
+
+        if redraw_needed {
+            tui.draw(&sample)?;
+            redraw_needed = false;
+        }
+    }
+
+    Ok(())
+}
+
+// 多主机面板：每台主机一个标签页，数字键或 Tab 切换
+fn run_dashboard(tui: &mut Tui, hosts: &[RemoteHost], interval: Duration) -> Result<()> {
+    let tick_rate = interval;
+    let mut last_tick = Instant::now();
+    let mut last_scroll = Instant::now();
+    let mut active = 0;
+    draw_host(tui, hosts, active)?;
+
+    while !interrupted() {
+        let mut redraw_needed = false;
+        if last_tick.elapsed() >= tick_rate {
+            redraw_needed = true;
+            last_tick = Instant::now();
+        }
+
+        let timeout = tick_rate.saturating_sub(last_tick.elapsed()).min(INPUT_POLL);
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                match key.code {
+                    _ if is_quit_key(&key) => break,
+                    KeyCode::Tab => {
+                        active = (active + 1) % hosts.len();
+                        redraw_needed = true;
+                    }
+                    KeyCode::BackTab => {
+                        active = (active + hosts.len() - 1) % hosts.len();
+                        redraw_needed = true;
+                    }
+                    KeyCode::Char(c @ '1'..='9') if (c as usize - '1' as usize) < hosts.len() => {
+                        active = c as usize - '1' as usize;
+                        redraw_needed = true;
+                    }
+                    _ => redraw_needed |= handle_view_key(tui, key, &mut last_scroll),
+                }
+            }
+        }
+
+        if redraw_needed {
+            draw_host(tui, hosts, active)?;
+        }
+    }
+
+    Ok(())
+}
+
+// 绘制当前标签页的主机，离线主机显示原因
+fn draw_host(tui: &mut Tui, hosts: &[RemoteHost], active: usize) -> Result<()> {
+    let states: Vec<HostState> = hosts.iter().map(RemoteHost::state).collect();
+    let titles = hosts.iter()
+        .zip(&states)
+        .enumerate()
+        .map(|(i, (host, state))| match state {
+            HostState::Online(_) => format!("{} {}", i + 1, host.name),
+            HostState::Connecting => format!("{} {} ({})", i + 1, host.name, tr(Text::Connecting)),
+            HostState::Offline(_) => format!("{} {} ({})", i + 1, host.name, tr(Text::Offline)),
+        })
+        .collect();
+    tui.set_tabs(titles, active);
+
+    match states.into_iter().nth(active) {
+        Some(HostState::Online(sample)) => tui.draw(&sample)?,
+        Some(HostState::Offline(reason)) => tui.draw_offline(&format!("{}: {}", tr(Text::Offline), reason))?,
+        _ => tui.draw_offline(&format!("{}...", tr(Text::Connecting)))?,
+    }
+    Ok(())
+}
+
+// raw 模式下 Ctrl-C 不会产生 SIGINT，而是作为按键送达，需要与 q 一样退出
+fn is_quit_key(key: &KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Char('q') => true,
+        KeyCode::Char('c') => key.modifiers.contains(KeyModifiers::CONTROL),
+        _ => false,
+    }
+}
+
+// TUI 和多主机面板共用的视图按键，返回是否需要重绘。
+// 多主机面板中 Tab 已用于切换主机，不会传到这里
+fn handle_view_key(tui: &mut Tui, key: KeyEvent, last_scroll: &mut Instant) -> bool {
+    match key.code {
+        KeyCode::Tab => tui.cycle_focus(false),
+        KeyCode::BackTab => tui.cycle_focus(true),
+        KeyCode::Char('i') => tui.toggle_cpu_details(),
+        KeyCode::Char('a') => tui.toggle_averaged(),
+        KeyCode::Char('p') => tui.request_snapshot(),
+        KeyCode::Char('e') => tui.export_history(),
+        KeyCode::Char('n') => tui.toggle_net_children(),
+        KeyCode::Char('s') => tui.cycle_process_sort(),
+        KeyCode::Char('w') => tui.toggle_swap_details(),
+        KeyCode::Enter => tui.open_disk_details(),
+        KeyCode::Esc => tui.close_disk_details(),
+        KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown
+        | KeyCode::Home | KeyCode::End => {
+            if last_scroll.elapsed() < SCROLL_RATE {
+                return false;
+            }
+            tui.handle_scroll(key);
+            *last_scroll = Instant::now();
+        }
+        _ => return false,
+    }
+    true
+}
+
+// 无界面模式的主循环：每个周期采样一次并交给 output 输出，完成 --count 次后退出
+fn run_headless(
+    cli: &Cli,
+    monitor: &mut Monitor,
+    mut recorder: Option<&mut Recorder>,
+    mut exec: Option<&mut ExecHook>,
+    mut summary: Option<&mut Summary>,
+    mut output: impl FnMut(&Sample) -> Result<()>,
+) -> Result<()> {
+    let interval = cli.interval;
+    let mut change_detector = cli.on_change.then(|| ChangeDetector::new(cli.change_delta));
+    let mut watcher = watcher(cli);
+    let mut cycles = 0;
+    let mut over_memory_limit = false;
+
+    loop {
+        let sample = monitor.sample();
+        output(&sample)?;
+        // 没有状态栏，超过上限时在标准错误警告一次，回落后再次超过时重新警告
+        if let Some(limit) = cli.self_mem_limit {
+            let warning = check_self_memory(monitor, limit.saturating_mul(1024 * 1024), cli.units);
+            if let Some(warning) = warning.as_deref().filter(|_| !over_memory_limit) {
+                eprintln!("{}", warning);
+            }
+            over_memory_limit = warning.is_some();
+        }
+        if let Some(summary) = summary.as_deref_mut() {
+            summary.update(&sample);
+        }
+        emit(&sample, recorder.as_deref_mut(), exec.as_deref_mut(), change_detector.as_mut())?;
+        watcher.notify(&sample);
+        cycles += 1;
+        if cli.count > 0 && cycles >= cli.count || !sleep_unless_interrupted(interval) {
+            return Ok(());
+        }
+        monitor.refresh();
+    }
+}
+
+// 分段睡眠以便及时响应中断，被中断时返回 false
+fn sleep_unless_interrupted(duration: Duration) -> bool {
+    let deadline = Instant::now() + duration;
+    while !interrupted() {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return true;
+        }
+        std::thread::sleep(remaining.min(Duration::from_millis(50)));
+    }
+    false
+}
+
+fn install_interrupt_handler() -> Result<()> {
+    let flag = Arc::new(AtomicBool::new(false));
+    for signal in [signal_hook::consts::SIGINT, signal_hook::consts::SIGTERM] {
+        signal_hook::flag::register(signal, Arc::clone(&flag))?;
+    }
+    let _ = INTERRUPTED.set(flag);
+    Ok(())
+}
+
+fn interrupted() -> bool {
+    INTERRUPTED.get().is_some_and(|flag| flag.load(Ordering::Relaxed))
+}
+
+// 单行模式：终端中用 \r 回到行首原地刷新并清除行尾残留，
+// 输出不是终端（管道、tmux 的 #() 等）时每个周期追加一行
+fn run_oneline(
+    cli: &Cli,
+    monitor: &mut Monitor,
+    recorder: Option<&mut Recorder>,
+    exec: Option<&mut ExecHook>,
+    summary: Option<&mut Summary>,
+) -> Result<()> {
+    let tty = std::io::stdout().is_terminal();
+    run_headless(cli, monitor, recorder, exec, summary, |sample| {
+        let line = cli.oneline_format.render(sample, cli.units);
+        let mut stdout = std::io::stdout().lock();
+        if tty {
+            write!(stdout, "\r{}\x1b[K", line)?;
+            stdout.flush()?;
+        } else {
+            writeln!(stdout, "{}", line)?;
+        }
+        Ok(())
+    })?;
+    if tty {
+        println!();
+    }
+    Ok(())
+}
+
+// 文本模式：逐行输出各项指标，周期之间以分隔线隔开（--quiet 时省略）
+fn print_text(sample: &Sample, cli: &Cli) -> Result<()> {
+    let (processes, units) = (cli.processes, cli.units);
+    let mut stdout = std::io::stdout().lock();
+    let options = FlatOptions {
+        averaged: false,
+        per_core: cli.per_core,
+        net_verbose: cli.net_verbose,
+        net_bits: cli.net_bits,
+        tables: cli.table,
+        units,
+        temp_unit: cli.theme.temp_unit,
+    };
+    for line in ui::flat_lines(sample, &options) {
+        writeln!(stdout, "{}", line)?;
+    }
+    if processes > 0 {
+        writeln!(stdout, "{:>8}  {:<20} {:>7} {:>12}", "PID", tr(Text::Process), "CPU%", tr(Text::Memory))?;
+        for (depth, process) in ProcessMonitor::tree_rows(&sample.processes, ProcessSort::Cpu).into_iter().take(processes) {
+            writeln!(
+                stdout,
+                "{:>8}  {:<20} {:>7.1} {:>12}",
+                process.pid,
+                ProcessMonitor::tree_name(depth, process),
+                process.cpu_usage,
+                MemoryMonitor::format_bytes(process.memory, units)
+            )?;
+        }
+    }
+    if !cli.quiet {
+        writeln!(stdout, "{}", "-".repeat(40))?;
+    }
+    Ok(())
+}
+
+/// JSON 模式：每个周期输出一个完整的 JSON 对象，数值保持原始单位。
+/// 默认每个对象占一行（NDJSON），--json-pretty 时缩进为多行。
+///
+/// 顶层字段：
+/// - `schema_version`: 结构版本（`SCHEMA_VERSION`），不兼容的变化会使其加一
+/// - `timestamp`: 采样时刻，Unix 毫秒
+/// - `cpu_info`、`uptime`、`boot_time`: CPU 型号、运行秒数、启动时刻（Unix 秒）
+/// - `cpu`、`load_average`、`memory`、`tcp`、`gpu`、`health`、`entropy`、`raid`、`connections`:
+///   未启用或采集失败时为 `null`
+/// - `disks`、`networks`: 数组，未启用时为空
+/// - `unavailable`: 连续采集失败的子系统（`cpu`、`memory`、`disk`、`network`、`gpu`）到最近一次错误的映射，没有时省略
+///
+/// `cpu` 中的各核心数组（`core_usage`、`core_average`、`frequency`、`max_frequency`）
+/// 只在 --per-core 时输出，避免核心很多的机器上每行过长。
+fn print_json(sample: &Sample, pretty: bool, per_core: bool) -> Result<()> {
+    let mut value = serde_json::to_value(sample)?;
+    if !per_core {
+        if let Some(cpu) = value.get_mut("cpu").and_then(serde_json::Value::as_object_mut) {
+            for key in ["core_usage", "core_average", "frequency", "max_frequency"] {
+                cpu.remove(key);
+            }
+        }
+    }
+    let json = if pretty {
+        serde_json::to_string_pretty(&value)?
+    } else {
+        serde_json::to_string(&value)?
+    };
+    writeln!(std::io::stdout().lock(), "{}", json)?;
+    Ok(())
+}
+
+// 行协议各行已带换行，整段一次写出
+fn print_influx(sample: &Sample, host: Option<&str>, per_core: bool) -> Result<()> {
+    write!(std::io::stdout().lock(), "{}", influx::render(sample, host, per_core))?;
+    Ok(())
+}
+
+// 只有 TUI 和带 --processes 的文本输出显示进程，其他输出不刷新也不采集进程表
+fn shows_processes(cli: &Cli) -> bool {
+    if cli.sample_and_exit_json || cli.serve.is_some() || cli.oneline || cli.format_string.is_some() {
+        return false;
+    }
+    match cli.format {
+        OutputFormat::Tui => true,
+        OutputFormat::Text => cli.processes > 0,
+        OutputFormat::Json | OutputFormat::Influx => false,
+    }
+}
+
+// 单次采集：成功时输出完整采样，CPU 和内存都采集失败时输出错误对象并以非零状态退出
+fn run_sample_and_exit(monitor: &mut Monitor, pretty: bool, per_core: bool) -> Result<()> {
+    let sample = monitor.sample();
+    if sample.cpu.is_none() && sample.memory.is_none() {
+        let error = serde_json::json!({ "error": tr(Text::BothCollectFailed) });
+        println!("{}", error);
+        std::process::exit(1);
+    }
+    print_json(&sample, pretty, per_core)
+}
+
+// 单次检查：按所选格式输出一次采样，任一阈值超出时在标准错误列出失败项并以状态码 2 退出
+fn run_once(cli: &Cli, monitor: &mut Monitor, recorder: Option<&mut Recorder>) -> Result<()> {
+    let sample = monitor.sample();
+    record(&sample, recorder)?;
+    match (&cli.format_string, cli.format) {
+        (Some(template), _) => writeln!(std::io::stdout().lock(), "{}", template.render(&sample))?,
+        (None, OutputFormat::Json) => print_json(&sample, cli.json_pretty, cli.per_core)?,
+        (None, OutputFormat::Influx) => print_influx(&sample, monitor.host_name().as_deref(), cli.per_core)?,
+        (None, _) => print_text(&sample, cli)?,
+    }
+
+    let failures = check_thresholds(cli, &sample);
+    if !failures.is_empty() {
+        for failure in &failures {
+            eprintln!("{}", failure);
+        }
+        std::process::exit(2);
+    }
+    Ok(())
+}
+
+// 返回超出阈值的检查项；设置了阈值但数据采集失败时同样视为失败
+fn check_thresholds(cli: &Cli, sample: &Sample) -> Vec<String> {
+    let mut failures = Vec::new();
+
+    if let Some(threshold) = cli.cpu_threshold {
+        match &sample.cpu {
+            Some(cpu) if cpu.total_usage as f64 > threshold => failures.push(fill(
+                Text::CpuUsageExceeds,
+                &[&format!("{:.1}%", cpu.total_usage), &format!("{:.1}%", threshold)],
+            )),
+            Some(_) => {}
+            None => failures.push(tr(Text::CpuCollectFailed).to_string()),
+        }
+    }
+
+    if let Some(threshold) = cli.mem_threshold {
+        match &sample.memory {
+            Some(mem) => {
+                let usage = DiskMonitor::usage_percentage(mem.total, mem.used);
+                if usage > threshold {
+                    failures.push(fill(Text::MemoryUsageExceeds, &[&format!("{:.1}%", usage), &format!("{:.1}%", threshold)]));
+                }
+            }
+            None => failures.push(tr(Text::MemoryCollectFailed).to_string()),
+        }
+    }
+
+    if let Some(threshold) = cli.disk_threshold {
+        for disk in &sample.disks {
+            if let Some(error) = &disk.error {
+                failures.push(fill(Text::DiskReadFailed, &[&disk.mount_point, error]));
+                continue;
+            }
+            let usage = DiskMonitor::usage_percentage(disk.total_space, disk.used_space);
+            if usage > threshold {
+                failures.push(fill(
+                    Text::DiskUsageExceeds,
+                    &[&disk.mount_point, &format!("{:.1}%", usage), &format!("{:.1}%", threshold)],
+                ));
+            }
+        }
+    }
+
+    // --once 只有一次采样，磁盘空间告警不等待 --watch-disk-grace，超出即失败
+    if let Some(threshold) = cli.watch_threshold.and_then(|t| t.disk) {
+        let mut watcher = Watcher::new(None, None, 1).with_disk(Some(threshold), Duration::ZERO);
+        failures.extend(watcher.check(sample));
+    }
+
+    failures
+}
+
+// --log 启用时向 CSV 文件追加一行
+fn record(sample: &Sample, recorder: Option<&mut Recorder>) -> Result<()> {
+    match recorder {
+        Some(recorder) => recorder.record(sample),
+        None => Ok(()),
+    }
+}
+
+// 标准输出用于 JSON、状态栏等机器读取的格式时，统计写到标准错误
+fn print_summary(summary: Option<&Summary>, cli: &Cli, stderr: bool) -> Result<()> {
+    let Some(summary) = summary else {
+        return Ok(());
+    };
+    let lines = summary.lines(cli.units, cli.net_bits);
+    if stderr {
+        let mut stderr = std::io::stderr().lock();
+        for line in lines {
+            writeln!(stderr, "{}", line)?;
+        }
+    } else {
+        let mut stdout = std::io::stdout().lock();
+        for line in lines {
+            writeln!(stdout, "{}", line)?;
+        }
+    }
+    Ok(())
+}
+
+// 向外部输出（--log 的 CSV 文件和 --exec 命令）写入一条记录，启用 --on-change 时两者都跳过变化不大的采样
+fn emit(
+    sample: &Sample,
+    recorder: Option<&mut Recorder>,
+    exec: Option<&mut ExecHook>,
+    change_detector: Option<&mut ChangeDetector>,
+) -> Result<()> {
+    if recorder.is_none() && exec.is_none() {
+        return Ok(());
+    }
+    if let Some(detector) = change_detector {
+        if !detector.should_emit(sample) {
+            return Ok(());
+        }
+    }
+    record(sample, recorder)?;
+    match exec {
+        Some(exec) => exec.send(sample),
+        None => Ok(()),
+    }
+}
+
+// 未设置 --watch-threshold 时不监视任何资源
+fn watcher(cli: &Cli) -> Watcher {
+    let threshold = cli.watch_threshold;
+    Watcher::new(
+        threshold.and_then(|t| t.cpu),
+        threshold.and_then(|t| t.memory),
+        cli.watch_samples,
+    )
+    .with_disk(threshold.and_then(|t| t.disk), cli.watch_disk_grace)
+}
+
+// 防止监控工具本身成为资源大户（例如历史缓冲区泄漏）
+fn check_self_memory(monitor: &mut Monitor, limit: u64, units: UnitStyle) -> Option<String> {
+    let used = monitor.self_memory()?;
+    (used > limit).then(|| fill(
+        Text::SelfMemoryExceeds,
+        &[&MemoryMonitor::format_bytes(used, units), &MemoryMonitor::format_bytes(limit, units)],
+    ))
+}
@@ -0,0 +1,53 @@
+use crate::monitor::{Sample, disk::DiskMonitor};
+
+/// 变化检测：只有关键指标相对上一次输出的记录变化超过阈值时才输出
+///
+/// 参与比较的是各项使用率（百分点）：CPU、内存、交换分区、每个磁盘和 GPU。
+/// 磁盘数量变化（挂载/卸载）也视为变化。网络速率波动频繁，不参与比较。
+pub struct ChangeDetector {
+    delta: f64,
+    last_emitted: Option<Vec<f64>>,
+}
+
+impl ChangeDetector {
+    pub fn new(delta: f64) -> Self {
+        Self {
+            delta,
+            last_emitted: None,
+        }
+    }
+
+    pub fn should_emit(&mut self, sample: &Sample) -> bool {
+        let current = Self::metrics(sample);
+        let changed = match &self.last_emitted {
+            Some(last) => {
+                last.len() != current.len()
+                    || last.iter().zip(&current).any(|(a, b)| (a - b).abs() >= self.delta)
+            }
+            None => true,
+        };
+
+        if changed {
+            self.last_emitted = Some(current);
+        }
+        changed
+    }
+
+    fn metrics(sample: &Sample) -> Vec<f64> {
+        let mut metrics = Vec::new();
+        if let Some(cpu) = &sample.cpu {
+            metrics.push(cpu.total_usage as f64);
+        }
+        if let Some(memory) = &sample.memory {
+            metrics.push(DiskMonitor::usage_percentage(memory.total, memory.used));
+            metrics.push(DiskMonitor::usage_percentage(memory.swap_total, memory.swap_used));
+        }
+        for disk in &sample.disks {
+            metrics.push(DiskMonitor::usage_percentage(disk.total_space, disk.used_space));
+        }
+        if let Some(gpu) = &sample.gpu {
+            metrics.push(gpu.utilization as f64);
+        }
+        metrics
+    }
+}
@@ -1,31 +1,691 @@
-use clap::{Parser, Args};
+use std::path::PathBuf;
+use std::time::Duration;
+use clap::{Parser, Args, ArgAction, CommandFactory, FromArgMatches, ValueEnum};
+use clap::parser::ValueSource;
+use serde::Deserialize;
+use crate::error::{LiteMonError, Result};
+use crate::i18n::{Lang, Text, fill, tr};
+use crate::monitor::RefreshSpec;
+use crate::monitor::health::HealthWeights;
+use crate::oneline::{self, OnelineFormat};
+use crate::template::Template;
+use crate::ui::{Levels, Theme};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 pub struct Cli {
-    /// 监控间隔（秒）
-    #[arg(short, long, default_value_t = 1)]
-    pub interval: u64,
+    /// 配置文件路径（默认 ~/.config/litemon/config.toml）
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+
+    /// 监控间隔（如 500ms、2s，不带单位时为秒）；TUI 中按键响应不受此影响
+    #[arg(short, long, value_parser = parse_interval, default_value = "1")]
+    pub interval: Duration,
+
+    /// CPU 总使用率的指数平滑系数（0-1]，越小越平稳，1 表示不平滑
+    #[arg(long, default_value_t = 1.0, value_parser = parse_smoothing)]
+    pub cpu_smooth: f32,
+
+    /// 完成指定次数的刷新和显示后退出（0 表示不限次数）
+    #[arg(long, default_value_t = 0)]
+    pub count: u64,
+
+    /// 输出格式：交互界面、纯文本或每行一个 JSON 对象
+    #[arg(long, value_enum, default_value_t = OutputFormat::Tui)]
+    pub format: OutputFormat,
+
+    /// 启动预热时长，如 500ms、2s（默认为 CPU 采样所需的最短间隔，0 表示不预热）
+    #[arg(long, value_parser = parse_duration)]
+    pub warmup: Option<Duration>,
+
+    /// 固定每页显示的 CPU 核心数（默认根据面板高度计算）
+    #[arg(long)]
+    pub cores_per_page: Option<usize>,
+
+    /// 磁盘仪表显示已用空间还是剩余空间
+    #[arg(long, value_enum, default_value_t = DiskDisplay::Used)]
+    pub disk_display: DiskDisplay,
+
+    /// 字节单位：binary 按 1024 进位显示 KiB/MiB/GiB，decimal 按 1000 进位显示 KB/MB/GB
+    #[arg(long, value_enum, default_value_t = UnitStyle::Binary)]
+    pub units: UnitStyle,
+
+    /// 温度单位：c 摄氏度，f 华氏度（也可在配置文件 [theme] 中设置）
+    #[arg(long, value_enum)]
+    pub temp_unit: Option<TempUnit>,
+
+    /// 界面语言：zh 中文，en 英文（默认按 LANG 环境变量选择）
+    #[arg(long, value_enum)]
+    pub lang: Option<Lang>,
+
+    /// 磁盘列表的排序方式
+    #[arg(long, value_enum, default_value_t = DiskSort::Name)]
+    pub sort_disks: DiskSort,
+
+    /// 文本模式下只输出指标，不输出标题、提示、分隔线和结束时的统计，便于脚本解析
+    #[arg(long)]
+    pub quiet: bool,
+
+    /// 不使用颜色（设置了非空的 NO_COLOR 环境变量时同样生效）
+    #[arg(long)]
+    pub no_color: bool,
+
+    /// 文本模式下以对齐的表格输出磁盘和网卡，便于在分页器中查看
+    #[arg(long)]
+    pub table: bool,
+
+    /// 文本模式下列出 CPU 占用最高的 N 个进程（默认不列出）
+    #[arg(long, default_value_t = 0)]
+    pub processes: usize,
+
+    /// 将子进程归入父进程，以进程树显示并累计整组的 CPU、内存和磁盘读写
+    #[arg(long)]
+    pub group_processes: bool,
+
+    /// 以纯文本列表显示所有指标，不使用仪表和颜色（便于读屏软件和小终端）
+    #[arg(long)]
+    pub flat: bool,
+
+    /// 健康分权重，格式为 CPU,内存,磁盘,交换分区（如 0.4,0.3,0.2,0.1）
+    #[arg(long, value_parser = parse_health_weights, default_value = "0.4,0.3,0.2,0.1")]
+    pub health_weights: HealthWeights,
+
+    /// 显示同一设备的所有挂载点（默认合并）
+    #[arg(long)]
+    pub no_dedup: bool,
+
+    /// 显示所有挂载点，包括 overlay、squashfs、tmpfs 和 /snap 下的挂载
+    #[arg(long)]
+    pub all_disks: bool,
+
+    /// 隐藏挂载点或文件系统类型匹配这些通配符的磁盘，逗号分隔（如 "/boot*,vfat"）
+    #[arg(long, value_delimiter = ',')]
+    pub disk_exclude: Vec<String>,
+
+    /// TUI 中 CPU 和内存走势保留的采样数（2 到 1000000），超过图宽时只显示最近的部分，其余用于 e 键导出
+    #[arg(long, default_value_t = 120, value_parser = clap::value_parser!(u64).range(2..=1_000_000))]
+    pub history: u64,
+
+    /// 网络速率按最近多少个采样间隔计算（1 表示只看最近一次）
+    #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u64).range(1..))]
+    pub net_window: u64,
+
+    /// 只显示名称匹配这些通配符的网卡，逗号分隔（如 "eth*,wl*"，支持 * 和 ?）
+    #[arg(long, value_delimiter = ',')]
+    pub net_include: Vec<String>,
+
+    /// 隐藏名称匹配这些通配符的网卡，逗号分隔（如 "veth*,docker*,lo"），优先于 --net-include
+    #[arg(long, value_delimiter = ',')]
+    pub net_exclude: Vec<String>,
+
+    /// 逐项检查各子系统能否采集到数据，结果输出到 stderr 后退出（排查缺失的指标）
+    #[arg(long)]
+    pub debug_capabilities: bool,
+
+    /// 报告宿主机内存，忽略容器的 cgroup 内存限制
+    #[arg(long)]
+    pub host_memory: bool,
+
+    /// 将子接口（如 eth0.100、eth0:1）汇总到父接口
+    #[arg(long)]
+    pub net_aggregate: bool,
+
+    /// 文本输出中显示各网卡的包数和错误数（TUI 中聚焦网络面板即可查看）
+    #[arg(long)]
+    pub net_verbose: bool,
+
+    /// 网络速率以比特/秒显示（Kbps/Mbps/Gbps，按 1000 进位），默认以字节/秒显示
+    #[arg(long)]
+    pub net_bits: bool,
+
+    /// TUI 网络面板带宽条的满量程（字节/秒），如 125000000 对应千兆网卡；默认按各接口观测到的峰值自动缩放
+    #[arg(long)]
+    pub net_max: Option<u64>,
+
+    /// 文本和 JSON 输出中包含各核心的使用率和频率（默认只输出总使用率）
+    #[arg(long)]
+    pub per_core: bool,
+
+    /// 跳过 GPU 初始化和采集
+    #[arg(long, conflicts_with = "require_gpu")]
+    pub no_gpu: bool,
+
+    /// GPU 数据的刷新间隔，如 500ms、5s（NVML 查询较慢时可以调大）
+    #[arg(long, value_parser = parse_duration, default_value = "1s", conflicts_with = "no_gpu")]
+    pub gpu_interval: Duration,
+
+    /// 未检测到 GPU 时报错退出
+    #[arg(long)]
+    pub require_gpu: bool,
+
+    /// 显示内核可用熵（仅 Linux）
+    #[arg(long)]
+    pub entropy: bool,
+
+    /// 显示各条 TCP 连接的本地/远程地址、状态和所属进程（Linux 读取 /proc/net/tcp，Windows 调用 GetExtendedTcpTable）
+    #[arg(long)]
+    pub connections: bool,
+
+    /// 显示软件 RAID 阵列状态（仅 Linux，读取 /proc/mdstat）
+    #[arg(long)]
+    pub raid: bool,
+
+    /// litemon 自身内存占用上限（MiB），超过时在 TUI 状态栏或标准错误警告
+    #[arg(long)]
+    pub self_mem_limit: Option<u64>,
+
+    /// 按自定义模板逐行输出（不启动 TUI），如 "CPU {cpu.total:.0}% MEM {mem.used_pct:.0}%"
+    #[arg(
+        long,
+        value_parser = Template::parse,
+        conflicts_with = "format",
+        long_help = format!("{}\n{}", tr(Text::FormatStringHelp), Template::help())
+    )]
+    pub format_string: Option<Template>,
+
+    /// 单行状态栏模式（如 tmux）：终端中原地刷新同一行，输出到管道时逐行追加
+    #[arg(long, conflicts_with_all = ["format_string", "serve", "hosts"])]
+    pub oneline: bool,
+
+    /// --oneline 的格式，如 "CPU {cpu} MEM {mem} ↓{net_rx}"
+    #[arg(
+        long,
+        value_parser = OnelineFormat::parse,
+        default_value = oneline::DEFAULT_FORMAT,
+        requires = "oneline",
+        long_help = format!("{}\n{}", tr(Text::OnelineFormatHelp), OnelineFormat::help())
+    )]
+    pub oneline_format: OnelineFormat,
+
+    /// JSON 输出（--format json、--sample-and-exit-json）使用缩进的多行格式，便于调试
+    #[arg(long)]
+    pub json_pretty: bool,
+
+    /// 预热后采集一次，向标准输出打印一个完整的 JSON 对象并退出
+    #[arg(long, conflicts_with_all = ["serve", "format_string", "hosts", "count", "oneline"])]
+    pub sample_and_exit_json: bool,
+
+    /// 采集并输出一次后退出；配合阈值参数可作为监控探针，超出阈值时退出码为 2
+    #[arg(long, conflicts_with_all = ["serve", "hosts", "count", "sample_and_exit_json", "oneline"])]
+    pub once: bool,
+
+    /// --once 的 CPU 总使用率阈值（百分比）
+    #[arg(long, value_parser = parse_percent, requires = "once")]
+    pub cpu_threshold: Option<f64>,
+
+    /// --once 的内存使用率阈值（百分比）
+    #[arg(long, value_parser = parse_percent, requires = "once")]
+    pub mem_threshold: Option<f64>,
+
+    /// --once 的磁盘使用率阈值（百分比），任一分区超出即失败
+    #[arg(long, value_parser = parse_percent, requires = "once")]
+    pub disk_threshold: Option<f64>,
+
+    /// 以无界面模式运行，并在该地址提供 /metrics（如 127.0.0.1:9184）
+    #[arg(long, conflicts_with = "format_string")]
+    pub serve: Option<String>,
+
+    /// 多主机面板：从文件读取远程 litemon --serve 地址（每行一个，可写作 名称=地址）
+    #[arg(long, conflicts_with_all = ["serve", "format_string"])]
+    pub hosts: Option<String>,
+
+    /// /metrics 的输出格式
+    #[arg(long, value_enum, default_value_t = MetricsFormat::Prometheus, requires = "serve")]
+    pub metrics_format: MetricsFormat,
+
+    /// 每个周期向该 CSV 文件追加一行（文件不存在时先写表头）
+    #[arg(long)]
+    pub log: Option<String>,
+
+    /// 每个周期将 JSON 采样写入该命令的标准输入（命令只启动一次）
+    #[arg(long)]
+    pub exec: Option<String>,
+
+    /// --exec 的命令退出后自动重启，而不是报错退出
+    #[arg(long, requires = "exec")]
+    pub exec_restart: bool,
+
+    /// 仅在关键指标变化超过阈值时输出记录（--log 和 --exec）
+    #[arg(long)]
+    pub on_change: bool,
+
+    /// --on-change 的变化阈值（百分点）
+    #[arg(long, default_value_t = 5.0, requires = "on_change")]
+    pub change_delta: f64,
+
+    /// 持续超限时发送桌面通知的阈值，如 cpu=90,mem=85,disk=95（百分比）
+    #[arg(long, value_parser = parse_watch_threshold)]
+    pub watch_threshold: Option<WatchThreshold>,
+
+    /// 连续多少次采样超过阈值才发送通知
+    #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u32).range(1..), requires = "watch_threshold")]
+    pub watch_samples: u32,
+
+    /// 磁盘使用率需持续超过 disk= 阈值多久才发送通知（如 30s、5m），忽略短暂的峰值
+    #[arg(long, value_parser = parse_duration, default_value = "1m", requires = "watch_threshold")]
+    pub watch_disk_grace: Duration,
 
     #[command(flatten)]
     pub monitors: MonitorArgs,
+
+    /// 仪表配色和温度单位，配色只能在配置文件中设置
+    #[arg(skip)]
+    pub theme: Theme,
 }
 
 #[derive(Args, Debug)]
 pub struct MonitorArgs {
     /// 是否监控 CPU
-    #[arg(long, default_value_t = true)]
+    #[arg(long, default_value_t = true, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
     pub cpu: bool,
 
     /// 是否监控内存
-    #[arg(long, default_value_t = true)]
+    #[arg(long, default_value_t = true, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
     pub memory: bool,
 
     /// 是否监控磁盘
-    #[arg(long, default_value_t = true)]
+    #[arg(long, default_value_t = true, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
     pub disk: bool,
 
     /// 是否监控网络
-    #[arg(long, default_value_t = true)]
+    #[arg(long, default_value_t = true, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
     pub network: bool,
-} 
\ No newline at end of file
+}
+
+impl MonitorArgs {
+    pub fn refresh_spec(&self) -> RefreshSpec {
+        RefreshSpec { cpu: self.cpu, memory: self.memory, disk: self.disk, network: self.network }
+    }
+}
+
+/// 配置文件内容，所有项均可省略
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    interval: Option<ConfigDuration>,
+    monitors: MonitorConfig,
+    thresholds: ThresholdConfig,
+    theme: ThemeConfig,
+}
+
+/// 配置文件中的时长：数字按秒计算，字符串与命令行的写法相同（如 "500ms"）
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum ConfigDuration {
+    Seconds(f64),
+    Text(String),
+}
+
+impl ConfigDuration {
+    fn interval(&self) -> std::result::Result<Duration, String> {
+        match self {
+            Self::Seconds(seconds) => parse_interval(&seconds.to_string()),
+            Self::Text(text) => parse_interval(text),
+        }
+    }
+}
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+struct MonitorConfig {
+    cpu: Option<bool>,
+    memory: Option<bool>,
+    disk: Option<bool>,
+    network: Option<bool>,
+}
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+struct ThresholdConfig {
+    cpu: Option<f64>,
+    mem: Option<f64>,
+    disk: Option<f64>,
+}
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+struct ThemeConfig {
+    normal: Option<String>,
+    warn: Option<String>,
+    critical: Option<String>,
+    // 各指标的 [警告, 严重] 分界（百分比）
+    cpu: Option<[f64; 2]>,
+    memory: Option<[f64; 2]>,
+    disk: Option<[f64; 2]>,
+    swap: Option<[f64; 2]>,
+    temp_unit: Option<TempUnit>,
+}
+
+impl ThemeConfig {
+    fn apply(self, theme: &mut Theme) -> Result<()> {
+        let colors = [
+            (self.normal, &mut theme.normal),
+            (self.warn, &mut theme.warn),
+            (self.critical, &mut theme.critical),
+        ];
+        for (value, field) in colors {
+            if let Some(value) = value {
+                *field = value.parse()
+                    .map_err(|_| LiteMonError::Config(fill(Text::ConfigInvalidColor, &[&value])))?;
+            }
+        }
+
+        if let Some(unit) = self.temp_unit {
+            theme.temp_unit = unit;
+        }
+
+        let levels = [
+            (self.cpu, &mut theme.cpu),
+            (self.memory, &mut theme.memory),
+            (self.disk, &mut theme.disk),
+            (self.swap, &mut theme.swap),
+        ];
+        for (value, field) in levels {
+            if let Some([warn, critical]) = value {
+                let warn = check_percent(warn).map_err(LiteMonError::Config)?;
+                let critical = check_percent(critical).map_err(LiteMonError::Config)?;
+                if warn > critical {
+                    return Err(LiteMonError::Config(fill(
+                        Text::ConfigLevelOrder, &[&warn.to_string(), &critical.to_string()],
+                    )));
+                }
+                *field = Levels::new(warn, critical);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Cli {
+    /// 解析命令行并合并配置文件。优先级：命令行参数 > 配置文件 > 内置默认值。
+    /// 只有未在命令行中给出的参数才会取配置文件中的值；
+    /// 默认路径的配置文件不存在时忽略，--config 指定的文件不存在或任何配置文件格式错误时报错
+    pub fn load() -> Result<Self> {
+        let matches = Self::command().get_matches();
+        let mut cli = Self::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+
+        let config = match &cli.config {
+            Some(path) => Some(Config::read(path)?),
+            None => match Config::default_path() {
+                Some(path) if path.exists() => Some(Config::read(&path)?),
+                _ => None,
+            },
+        };
+        if let Some(config) = config {
+            cli.apply(config, &matches)?;
+        }
+        // 温度单位存放在主题中，TUI 和文本输出共用
+        if let Some(unit) = cli.temp_unit {
+            cli.theme.temp_unit = unit;
+        }
+        Ok(cli)
+    }
+
+    /// 是否输出颜色：--no-color 或非空的 NO_COLOR 环境变量（见 no-color.org）均可关闭
+    pub fn use_color(&self) -> bool {
+        !self.no_color && std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+    }
+
+    fn apply(&mut self, config: Config, matches: &clap::ArgMatches) -> Result<()> {
+        let unset = |id: &str| matches.value_source(id) != Some(ValueSource::CommandLine);
+
+        if let Some(interval) = config.interval.filter(|_| unset("interval")) {
+            self.interval = interval.interval().map_err(LiteMonError::Config)?;
+        }
+
+        let monitors = [
+            ("cpu", config.monitors.cpu, &mut self.monitors.cpu),
+            ("memory", config.monitors.memory, &mut self.monitors.memory),
+            ("disk", config.monitors.disk, &mut self.monitors.disk),
+            ("network", config.monitors.network, &mut self.monitors.network),
+        ];
+        for (id, value, field) in monitors {
+            if let Some(value) = value.filter(|_| unset(id)) {
+                *field = value;
+            }
+        }
+
+        let thresholds = [
+            ("cpu_threshold", config.thresholds.cpu, &mut self.cpu_threshold),
+            ("mem_threshold", config.thresholds.mem, &mut self.mem_threshold),
+            ("disk_threshold", config.thresholds.disk, &mut self.disk_threshold),
+        ];
+        for (id, value, field) in thresholds {
+            if let Some(value) = value.filter(|_| unset(id)) {
+                *field = Some(check_percent(value).map_err(LiteMonError::Config)?);
+            }
+        }
+
+        config.theme.apply(&mut self.theme)
+    }
+}
+
+impl Config {
+    fn read(path: &std::path::Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| LiteMonError::Config(fill(Text::ConfigUnreadable, &[&path.display().to_string(), &e.to_string()])))?;
+        toml::from_str(&content)
+            .map_err(|e| LiteMonError::Config(fill(Text::ConfigMalformed, &[&path.display().to_string(), &e.to_string()])))
+    }
+
+    // $XDG_CONFIG_HOME/litemon/config.toml，未设置时为 ~/.config/litemon/config.toml
+    fn default_path() -> Option<PathBuf> {
+        let base = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(base.join("litemon").join("config.toml"))
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// 终端交互界面
+    Tui,
+    /// 每个周期输出一段文本
+    Text,
+    /// 每个周期输出一行 JSON（NDJSON）
+    Json,
+    /// 每个周期输出 InfluxDB 行协议，每个 measurement 一行
+    Influx,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiskDisplay {
+    /// 显示已用空间
+    Used,
+    /// 显示剩余空间
+    Free,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnitStyle {
+    /// 1024 进位，IEC 单位（KiB、MiB、GiB）
+    Binary,
+    /// 1000 进位，SI 单位（KB、MB、GB）
+    Decimal,
+}
+
+#[derive(ValueEnum, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TempUnit {
+    /// 摄氏度
+    #[default]
+    #[value(name = "c")]
+    #[serde(rename = "c")]
+    Celsius,
+    /// 华氏度
+    #[value(name = "f")]
+    #[serde(rename = "f")]
+    Fahrenheit,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiskSort {
+    /// 按设备名，其次按挂载点
+    Name,
+    /// 按使用率从高到低
+    Usage,
+    /// 按总容量从大到小
+    Size,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetricsFormat {
+    /// Prometheus 文本格式 0.0.4
+    Prometheus,
+    /// OpenMetrics 1.0（带 # UNIT 和结尾的 # EOF）
+    Openmetrics,
+}
+
+fn parse_health_weights(s: &str) -> std::result::Result<HealthWeights, String> {
+    let values = s
+        .split(',')
+        .map(|part| part.trim().parse::<f64>().map_err(|e| fill(Text::ArgInvalidWeight, &[part, &e.to_string()])))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    match values.as_slice() {
+        [cpu, memory, disk, swap] if values.iter().all(|w| *w >= 0.0) => Ok(HealthWeights {
+            cpu: *cpu,
+            memory: *memory,
+            disk: *disk,
+            swap: *swap,
+        }),
+        [_, _, _, _] => Err(tr(Text::ArgNegativeWeight).to_string()),
+        _ => Err(tr(Text::ArgWeightCount).to_string()),
+    }
+}
+
+/// --watch-threshold 的各项阈值，未给出的项不监视
+#[derive(Debug, Clone, Copy)]
+pub struct WatchThreshold {
+    pub cpu: Option<f64>,
+    pub memory: Option<f64>,
+    pub disk: Option<f64>,
+}
+
+fn parse_watch_threshold(s: &str) -> std::result::Result<WatchThreshold, String> {
+    let mut threshold = WatchThreshold { cpu: None, memory: None, disk: None };
+    for part in s.split(',') {
+        let (name, value) = part
+            .split_once('=')
+            .ok_or_else(|| fill(Text::ArgInvalidThreshold, &[part]))?;
+        let value = Some(parse_percent(value)?);
+        match name.trim() {
+            "cpu" => threshold.cpu = value,
+            "mem" | "memory" => threshold.memory = value,
+            "disk" => threshold.disk = value,
+            name => return Err(fill(Text::ArgUnknownResource, &[name])),
+        }
+    }
+    Ok(threshold)
+}
+
+fn parse_smoothing(s: &str) -> std::result::Result<f32, String> {
+    let value: f32 = s.trim().parse().map_err(|_| fill(Text::ArgInvalidSmoothing, &[s]))?;
+    if value > 0.0 && value <= 1.0 {
+        Ok(value)
+    } else {
+        Err(fill(Text::ArgSmoothingRange, &[s]))
+    }
+}
+
+fn parse_percent(s: &str) -> std::result::Result<f64, String> {
+    let value: f64 = s.trim().parse().map_err(|_| fill(Text::ArgInvalidPercent, &[s]))?;
+    check_percent(value)
+}
+
+fn check_percent(value: f64) -> std::result::Result<f64, String> {
+    if (0.0..=100.0).contains(&value) {
+        Ok(value)
+    } else {
+        Err(fill(Text::ArgPercentRange, &[&value.to_string()]))
+    }
+}
+
+// 采样间隔不能为 0，否则主循环会空转
+fn parse_interval(s: &str) -> std::result::Result<Duration, String> {
+    let interval = parse_duration(s)?;
+    if interval.is_zero() {
+        return Err(fill(Text::ArgZeroInterval, &[s]));
+    }
+    Ok(interval)
+}
+
+// 解析时长：支持 ms、s、m 后缀，不带后缀的数字按秒计算（可为小数）
+pub fn parse_duration(s: &str) -> std::result::Result<Duration, String> {
+    let s = s.trim();
+    let (number, unit) = if let Some(number) = s.strip_suffix("ms") {
+        (number, 0.001)
+    } else if let Some(number) = s.strip_suffix('s') {
+        (number, 1.0)
+    } else if let Some(number) = s.strip_suffix('m') {
+        (number, 60.0)
+    } else {
+        (s, 1.0)
+    };
+
+    let value: f64 = number
+        .trim()
+        .parse()
+        .map_err(|_| fill(Text::ArgInvalidDuration, &[s]))?;
+    if !value.is_finite() {
+        return Err(fill(Text::ArgNonFiniteDuration, &[s]));
+    }
+    if value < 0.0 {
+        return Err(fill(Text::ArgNegativeDuration, &[s]));
+    }
+    Duration::try_from_secs_f64(value * unit).map_err(|_| fill(Text::ArgDurationTooLong, &[s]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_units() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1s"), Ok(Duration::from_secs(1)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration(" 1.5s "), Ok(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn bare_number_is_seconds() {
+        assert_eq!(parse_duration("2"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_duration("0.5"), Ok(Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn invalid_duration() {
+        for input in ["", "abc", "5h", "ms", "-1", "-500ms", "NaN", "inf"] {
+            assert!(parse_duration(input).is_err(), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn overflowing_duration_is_rejected() {
+        for input in ["1e30", "1e30ms", "1e300m"] {
+            assert!(parse_duration(input).is_err(), "{:?}", input);
+        }
+        assert!(parse_interval("1e30").is_err());
+    }
+
+    #[test]
+    fn interval_rejects_zero() {
+        assert_eq!(parse_interval("500ms"), Ok(Duration::from_millis(500)));
+        assert!(parse_interval("0").is_err());
+        assert!(parse_interval("0ms").is_err());
+    }
+
+    #[test]
+    fn config_interval() {
+        let interval = |toml: &str| {
+            toml::from_str::<Config>(toml).unwrap().interval.unwrap().interval()
+        };
+        assert_eq!(interval("interval = 2"), Ok(Duration::from_secs(2)));
+        assert_eq!(interval("interval = 0.25"), Ok(Duration::from_millis(250)));
+        assert_eq!(interval("interval = \"500ms\""), Ok(Duration::from_millis(500)));
+        assert!(interval("interval = 0").is_err());
+        assert!(interval("interval = \"0s\"").is_err());
+        assert!(interval("interval = \"fast\"").is_err());
+    }
+}
@@ -8,6 +8,31 @@ pub enum LiteMonError {
     NoGpuFound,
     #[error("GPU error: {0}")]
     Gpu(#[from] nvml_wrapper::error::NvmlError),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Exec command exited: {0}")]
+    ExecExited(String),
+    #[error("Config error: {0}")]
+    Config(String),
 }
 
-pub type Result<T> = std::result::Result<T, LiteMonError>; 
\ No newline at end of file
+pub type Result<T> = std::result::Result<T, LiteMonError>; 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nvml_wrapper::error::NvmlError;
+
+    #[test]
+    fn no_gpu_found_message() {
+        assert_eq!(LiteMonError::NoGpuFound.to_string(), "No GPU found");
+    }
+
+    // GpuMonitor 依赖 ? 将 NVML 错误转换为 LiteMonError
+    #[test]
+    fn nvml_error_converts() {
+        fn query() -> Result<()> {
+            Err(NvmlError::NotSupported)?
+        }
+        assert!(matches!(query(), Err(LiteMonError::Gpu(NvmlError::NotSupported))));
+    }
+}
@@ -0,0 +1,204 @@
+use std::io::Write;
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, SyncSender, TrySendError};
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::error::{Result, LiteMonError};
+use crate::monitor::Sample;
+
+// 写入线程最多缓存的行数，子进程处理不过来时丢弃新的采样而不是阻塞采样循环
+const QUEUE_LEN: usize = 16;
+// 关闭 stdin 后等待子进程自行退出的时间，超时则强制结束
+const EXIT_GRACE: Duration = Duration::from_secs(1);
+
+/// 外部命令钩子：子进程只启动一次，每个采样周期向其 stdin 写入一行 JSON
+pub struct ExecHook {
+    command: String,
+    restart: bool,  // 子进程退出后是否自动重启
+    worker: Worker,
+}
+
+impl ExecHook {
+    pub fn spawn(command: &str, restart: bool) -> Result<Self> {
+        Ok(Self {
+            command: command.to_string(),
+            restart,
+            worker: Worker::spawn(command)?,
+        })
+    }
+
+    pub fn send(&mut self, sample: &Sample) -> Result<()> {
+        let mut line = serde_json::to_string(sample)?;
+        line.push('\n');
+
+        let line = match self.worker.try_send(line) {
+            Ok(()) | Err(TrySendError::Full(_)) => return Ok(()),
+            Err(TrySendError::Disconnected(line)) => line,
+        };
+
+        // 写入线程已退出说明子进程已退出或关闭了 stdin
+        let status = match self.worker.child.try_wait()? {
+            Some(status) => status.to_string(),
+            None => "stdin closed".to_string(),
+        };
+        if !self.restart {
+            return Err(LiteMonError::ExecExited(status));
+        }
+
+        // 替换时旧的子进程随 Worker 一起被回收
+        self.worker = Worker::spawn(&self.command)?;
+        self.worker.try_send(line)
+            .map_err(|_| LiteMonError::ExecExited(status))
+    }
+}
+
+// 一个运行中的子进程及向其 stdin 写入的线程
+struct Worker {
+    child: Child,
+    lines: Option<SyncSender<String>>,
+}
+
+impl Worker {
+    fn spawn(command: &str) -> Result<Self> {
+        let mut child = Self::spawn_child(command)?;
+        let stdin = child.stdin.take();
+        let (lines, queue) = mpsc::sync_channel::<String>(QUEUE_LEN);
+
+        // 写入可能因子进程不读 stdin 而阻塞，放在独立线程中；写入失败或通道关闭时退出并关闭 stdin
+        thread::spawn(move || {
+            let Some(mut stdin) = stdin else {
+                return;
+            };
+            for line in queue {
+                if stdin.write_all(line.as_bytes()).and_then(|_| stdin.flush()).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self { child, lines: Some(lines) })
+    }
+
+    fn try_send(&self, line: String) -> std::result::Result<(), TrySendError<String>> {
+        match &self.lines {
+            Some(lines) => lines.try_send(line),
+            None => Err(TrySendError::Disconnected(line)),
+        }
+    }
+
+    // 等待子进程退出，超时返回 false
+    fn wait_exit(&mut self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        loop {
+            match self.child.try_wait() {
+                Ok(Some(_)) | Err(_) => return true,
+                Ok(None) if Instant::now() >= deadline => return false,
+                Ok(None) => thread::sleep(Duration::from_millis(10)),
+            }
+        }
+    }
+
+    // 通过系统 shell 启动，以支持管道和参数；输出丢弃以免干扰 TUI
+    fn spawn_child(command: &str) -> Result<Child> {
+        #[cfg(windows)]
+        let mut cmd = {
+            let mut cmd = Command::new("cmd");
+            cmd.args(["/C", command]);
+            cmd
+        };
+        #[cfg(not(windows))]
+        let mut cmd = {
+            let mut cmd = Command::new("sh");
+            cmd.args(["-c", command]);
+            cmd
+        };
+
+        Ok(cmd
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?)
+    }
+}
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        // 关闭通道，写入线程写完剩余的行后关闭 stdin，子进程读到 EOF 后应自行退出；
+        // 不读 stdin 或不退出的子进程在宽限期后强制结束，避免退出时卡住
+        drop(self.lines.take());
+        if !self.wait_exit(EXIT_GRACE) {
+            let _ = self.child.kill();
+        }
+        let _ = self.child.wait();
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn output_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("litemon-exec-{}-{}", std::process::id(), name))
+    }
+
+    // 子进程退出后写入线程才会发现管道断开，需要多发几次
+    fn send_until_error(hook: &mut ExecHook) -> Option<LiteMonError> {
+        let sample = Sample::empty();
+        for _ in 0..200 {
+            if let Err(e) = hook.send(&sample) {
+                return Some(e);
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        None
+    }
+
+    #[test]
+    fn lines_are_flushed_before_exit() {
+        let path = output_path("flush");
+        let mut hook = ExecHook::spawn(&format!("cat > '{}'", path.display()), false).unwrap();
+        for _ in 0..3 {
+            hook.send(&Sample::empty()).unwrap();
+        }
+        drop(hook);
+
+        let written = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_file(&path);
+        assert_eq!(written.lines().count(), 3);
+        assert!(written.lines().all(|line| line.starts_with('{')));
+    }
+
+    #[test]
+    fn exited_child_is_reported() {
+        let mut hook = ExecHook::spawn("exit 3", false).unwrap();
+        assert!(matches!(send_until_error(&mut hook), Some(LiteMonError::ExecExited(_))));
+    }
+
+    #[test]
+    fn exited_child_is_restarted() {
+        let mut hook = ExecHook::spawn("exit 3", true).unwrap();
+        let first = hook.worker.child.id();
+        for _ in 0..200 {
+            let _ = hook.send(&Sample::empty());
+            if hook.worker.child.id() != first {
+                return;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        panic!("子进程未被重启");
+    }
+
+    #[test]
+    fn child_ignoring_stdin_is_killed_on_drop() {
+        let mut hook = ExecHook::spawn("sleep 30", false).unwrap();
+        // 超出管道缓冲区的数据会阻塞写入线程，但不能阻塞 send
+        for _ in 0..QUEUE_LEN * 4 {
+            hook.send(&Sample::empty()).unwrap();
+        }
+        let start = Instant::now();
+        drop(hook);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,276 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, UNIX_EPOCH};
+use crate::cli::MetricsFormat;
+use crate::error::Result;
+use crate::monitor::Sample;
+
+// 单个客户端连接的读写超时
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 指标导出服务：后台线程响应 GET /metrics、GET /sample 和 GET /healthz，内容取自最近一次采样
+pub struct Exporter {
+    latest: Arc<Mutex<Option<Sample>>>,
+}
+
+impl Exporter {
+    pub fn serve(addr: &str, format: MetricsFormat) -> Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let latest = Arc::new(Mutex::new(None));
+        let shared = Arc::clone(&latest);
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                // 单个客户端出错不影响后续请求
+                let _ = Self::handle(stream, &shared, format);
+            }
+        });
+
+        Ok(Self { latest })
+    }
+
+    pub fn update(&self, sample: &Sample) {
+        if let Ok(mut latest) = self.latest.lock() {
+            *latest = Some(sample.clone());
+        }
+    }
+
+    fn handle(
+        stream: TcpStream,
+        latest: &Mutex<Option<Sample>>,
+        format: MetricsFormat,
+    ) -> std::io::Result<()> {
+        // 防止慢客户端阻塞导出线程：不发请求或不读响应的连接都在超时后放弃
+        stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+        stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+        let mut reader = BufReader::new(&stream);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        // 读完请求头，避免客户端收到连接重置
+        let mut header = String::new();
+        while reader.read_line(&mut header)? > 2 {
+            header.clear();
+        }
+
+        let mut parts = request_line.split_whitespace();
+        let (method, path) = (parts.next(), parts.next());
+        let path = path.map(|p| p.split('?').next().unwrap_or(p));
+
+        let (status, content_type, body) = match (method, path) {
+            (Some("GET"), Some("/metrics")) => {
+                let sample = latest.lock().ok().and_then(|latest| latest.clone());
+                match sample {
+                    Some(sample) => ("200 OK", format.content_type(), render(&sample, format)),
+                    None => ("503 Service Unavailable", "text/plain; charset=utf-8", "no sample yet\n".to_string()),
+                }
+            }
+            // 完整采样的 JSON，供 --hosts 多主机面板拉取
+            (Some("GET"), Some("/sample")) => {
+                let sample = latest.lock().ok().and_then(|latest| latest.clone());
+                match sample.map(|sample| serde_json::to_string(&sample)) {
+                    Some(Ok(json)) => ("200 OK", "application/json", json),
+                    _ => ("503 Service Unavailable", "text/plain; charset=utf-8", "no sample yet\n".to_string()),
+                }
+            }
+            // 存活探针：最近一次采样取得了数据才算正常，反映采集是否真正工作，而不只是进程在运行
+            (Some("GET"), Some("/healthz")) => {
+                let healthy = latest.lock().ok().is_some_and(|latest| latest.as_ref().is_some_and(collected));
+                if healthy {
+                    ("200 OK", "text/plain; charset=utf-8", "ok\n".to_string())
+                } else {
+                    ("503 Service Unavailable", "text/plain; charset=utf-8", "no successful sample\n".to_string())
+                }
+            }
+            _ => ("404 Not Found", "text/plain; charset=utf-8", "not found\n".to_string()),
+        };
+
+        let mut stream = &stream;
+        write!(
+            stream,
+            "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            content_type,
+            body.len(),
+            body
+        )?;
+        stream.flush()
+    }
+}
+
+impl MetricsFormat {
+    fn content_type(self) -> &'static str {
+        match self {
+            MetricsFormat::Prometheus => "text/plain; version=0.0.4; charset=utf-8",
+            MetricsFormat::Openmetrics => "application/openmetrics-text; version=1.0.0; charset=utf-8",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Gauge,
+    Counter,
+}
+
+// 一个指标族；名称已带单位后缀，计数器的样本名另加 _total
+struct Family {
+    name: &'static str,
+    help: &'static str,
+    kind: Kind,
+    unit: Option<&'static str>,
+    samples: Vec<(String, f64)>,  // (标签, 值)
+}
+
+impl Family {
+    fn new(name: &'static str, help: &'static str, kind: Kind, unit: Option<&'static str>) -> Self {
+        Self { name, help, kind, unit, samples: Vec::new() }
+    }
+
+    fn push(&mut self, labels: &[(&str, &str)], value: f64) {
+        let labels = if labels.is_empty() {
+            String::new()
+        } else {
+            let pairs: Vec<String> = labels.iter()
+                .map(|(key, value)| format!("{}=\"{}\"", key, escape_label(value)))
+                .collect();
+            format!("{{{}}}", pairs.join(","))
+        };
+        self.samples.push((labels, value));
+    }
+
+    fn write(&self, out: &mut String, format: MetricsFormat) {
+        if self.samples.is_empty() {
+            return;
+        }
+        let (kind, sample_suffix) = match self.kind {
+            Kind::Gauge => ("gauge", ""),
+            Kind::Counter => ("counter", "_total"),
+        };
+
+        match format {
+            // 旧版文本格式的 TYPE/HELP 使用样本全名，且没有 UNIT
+            MetricsFormat::Prometheus => {
+                out.push_str(&format!("# HELP {}{} {}\n", self.name, sample_suffix, self.help));
+                out.push_str(&format!("# TYPE {}{} {}\n", self.name, sample_suffix, kind));
+            }
+            MetricsFormat::Openmetrics => {
+                out.push_str(&format!("# TYPE {} {}\n", self.name, kind));
+                if let Some(unit) = self.unit {
+                    out.push_str(&format!("# UNIT {} {}\n", self.name, unit));
+                }
+                out.push_str(&format!("# HELP {} {}\n", self.name, self.help));
+            }
+        }
+        for (labels, value) in &self.samples {
+            out.push_str(&format!("{}{}{} {}\n", self.name, sample_suffix, labels, value));
+        }
+    }
+}
+
+// 标签值中的反斜杠、双引号和换行需要转义
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+// 采样中至少有一项启用的监控取得了数据
+fn collected(sample: &Sample) -> bool {
+    sample.cpu.is_some() || sample.memory.is_some() || !sample.disks.is_empty() || !sample.networks.is_empty()
+}
+
+/// 将一次采样渲染为 Prometheus 文本格式或 OpenMetrics 格式
+pub fn render(sample: &Sample, format: MetricsFormat) -> String {
+    let mut families = Vec::new();
+
+    let mut timestamp = Family::new(
+        "litemon_sample_timestamp_seconds", "Time of the latest sample", Kind::Gauge, Some("seconds"));
+    let seconds = sample.timestamp.duration_since(UNIX_EPOCH).map_or(0.0, |d| d.as_secs_f64());
+    timestamp.push(&[], seconds);
+    families.push(timestamp);
+
+    if let Some(cpu) = &sample.cpu {
+        let mut total = Family::new(
+            "litemon_cpu_usage_ratio", "Overall CPU usage", Kind::Gauge, Some("ratio"));
+        total.push(&[], cpu.total_usage as f64 / 100.0);
+        families.push(total);
+
+        let mut cores = Family::new(
+            "litemon_cpu_core_usage_ratio", "Per-core CPU usage", Kind::Gauge, Some("ratio"));
+        for (i, usage) in cpu.core_usage.iter().enumerate() {
+            cores.push(&[("core", &i.to_string())], *usage as f64 / 100.0);
+        }
+        families.push(cores);
+    }
+
+    if let Some(mem) = &sample.memory {
+        let memory = [
+            ("litemon_memory_total_bytes", "Total physical memory", mem.total),
+            ("litemon_memory_used_bytes", "Used physical memory", mem.used),
+            ("litemon_memory_available_bytes", "Available physical memory", mem.available),
+            ("litemon_swap_total_bytes", "Total swap space", mem.swap_total),
+            ("litemon_swap_used_bytes", "Used swap space", mem.swap_used),
+        ];
+        for (name, help, value) in memory {
+            let mut family = Family::new(name, help, Kind::Gauge, Some("bytes"));
+            family.push(&[], value as f64);
+            families.push(family);
+        }
+    }
+
+    let mut disk_total = Family::new(
+        "litemon_disk_total_bytes", "Total filesystem size", Kind::Gauge, Some("bytes"));
+    let mut disk_available = Family::new(
+        "litemon_disk_available_bytes", "Filesystem space available to unprivileged users", Kind::Gauge, Some("bytes"));
+    for disk in sample.disks.iter().filter(|disk| disk.error.is_none()) {
+        let labels = [("device", disk.name.as_str()), ("mount", disk.mount_point.as_str())];
+        disk_total.push(&labels, disk.total_space as f64);
+        disk_available.push(&labels, disk.available_space as f64);
+    }
+    families.push(disk_total);
+    families.push(disk_available);
+
+    let mut received = Family::new(
+        "litemon_network_received_bytes", "Bytes received per interface", Kind::Counter, Some("bytes"));
+    let mut transmitted = Family::new(
+        "litemon_network_transmitted_bytes", "Bytes transmitted per interface", Kind::Counter, Some("bytes"));
+    for net in &sample.networks {
+        let labels = [("interface", net.interface_name.as_str())];
+        received.push(&labels, net.total_received as f64);
+        transmitted.push(&labels, net.total_transmitted as f64);
+    }
+    families.push(received);
+    families.push(transmitted);
+
+    if let Some(gpu) = &sample.gpu {
+        let labels = [("name", gpu.name.as_str())];
+        let mut utilization = Family::new(
+            "litemon_gpu_utilization_ratio", "GPU utilization", Kind::Gauge, Some("ratio"));
+        utilization.push(&labels, gpu.utilization as f64 / 100.0);
+        let mut memory = Family::new(
+            "litemon_gpu_memory_used_bytes", "Used GPU memory", Kind::Gauge, Some("bytes"));
+        memory.push(&labels, gpu.memory_used as f64);
+        let mut temperature = Family::new(
+            "litemon_gpu_temperature_celsius", "GPU temperature", Kind::Gauge, Some("celsius"));
+        temperature.push(&labels, gpu.temperature as f64);
+        families.extend([utilization, memory, temperature]);
+    }
+
+    if let Some(health) = &sample.health {
+        let mut score = Family::new(
+            "litemon_health_score", "Composite health score from 0 to 100", Kind::Gauge, None);
+        score.push(&[], health.score);
+        families.push(score);
+    }
+
+    let mut out = String::new();
+    for family in &families {
+        family.write(&mut out, format);
+    }
+    if format == MetricsFormat::Openmetrics {
+        out.push_str("# EOF\n");
+    }
+    out
+}
@@ -0,0 +1,751 @@
+use std::sync::OnceLock;
+use clap::ValueEnum;
+
+/// 界面语言
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Lang {
+    /// 中文
+    Zh,
+    /// English
+    En,
+}
+
+impl Lang {
+    /// 按 LANG 环境变量推断：zh_* 为中文，其他语言为英文；
+    /// 未设置或为 C/POSIX 时保持原先的中文界面
+    pub fn from_env() -> Self {
+        let lang = std::env::var("LANG").unwrap_or_default();
+        let is_default = lang.is_empty() || lang == "C" || lang == "POSIX" || lang.starts_with("C.");
+        if is_default || lang.starts_with("zh") {
+            Lang::Zh
+        } else {
+            Lang::En
+        }
+    }
+}
+
+static LANG: OnceLock<Lang> = OnceLock::new();
+
+/// 设置界面语言，只能调用一次，之后的调用被忽略
+pub fn set_lang(lang: Lang) {
+    let _ = LANG.set(lang);
+}
+
+// 未设置时按环境变量取语言但不固定下来：解析命令行（帮助和参数错误）时 --lang 尚未生效
+pub fn lang() -> Lang {
+    LANG.get().copied().unwrap_or_else(Lang::from_env)
+}
+
+/// 当前语言下的文本
+pub fn tr(text: Text) -> &'static str {
+    match lang() {
+        Lang::Zh => zh(text),
+        Lang::En => en(text),
+    }
+}
+
+/// 依次用 args 替换模板中的 `{}`，用于两种语言语序不同的句子
+pub fn fill(text: Text, args: &[&str]) -> String {
+    let mut result = String::new();
+    let mut rest = tr(text);
+    for arg in args {
+        match rest.split_once("{}") {
+            Some((before, after)) => {
+                result.push_str(before);
+                result.push_str(arg);
+                rest = after;
+            }
+            None => break,
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 界面文本的键。标签类文本直接与数值拼接；带 `{}` 的是模板，通过 [`fill`] 填入参数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Text {
+    // 通用
+    Used,
+    Total,
+    Available,
+    Usage,
+    Read,
+    Write,
+    ReadShort,
+    WriteShort,
+    Receive,
+    Transmit,
+    Disabled,
+    ReadFailed,
+    ReadOnly,
+    Removable,
+    Yes,
+    No,
+    Unknown,
+    Memory,
+    Disk,
+    Swap,
+    SwapShort,
+    Points,
+    Frequency,
+    Core,
+    Process,
+    Packets,
+    Errors,
+    NoAddress,
+    DiskRead,
+    DiskWrite,
+    Degraded,
+    Unavailable,
+    Healthy,
+    TcpConnections,
+    LocalAddress,
+    RemoteAddress,
+    Established,
+    Listen,
+    Handshake,
+
+    // 文本输出和检查
+    Banner,
+    QuitHint,
+    BothCollectFailed,
+    CpuCollectFailed,
+    MemoryCollectFailed,
+    CpuUsageExceeds,
+    MemoryUsageExceeds,
+    DiskUsageExceeds,
+    DiskReadFailed,
+    SelfMemoryExceeds,
+    CpuAlert,
+    MemoryAlert,
+    DiskAlert,
+    DiskAlertStatus,
+    Summary,
+    SummaryStat,
+    CpuUsage,
+    MemoryUsage,
+    NetworkPeak,
+    Uptime,
+    HealthScore,
+    Bottleneck,
+    CpuModel,
+    CpuTotalUsage,
+    LoadAverage,
+    CpuAverageFrequency,
+    CoreLine,
+    MemoryUsed,
+    MemoryAvailable,
+    SwapUsed,
+    DiskLine,
+    DiskInodes,
+    GpuModel,
+    GpuUsage,
+    GpuTemperature,
+    GpuFan,
+    GpuPower,
+    VramUsed,
+    GpuThrottle,
+    KernelEntropy,
+    RaidLine,
+
+    // 表格列
+    Device,
+    MountPoint,
+    Type,
+    Interface,
+    TotalReceived,
+    TotalTransmitted,
+    PacketsReceived,
+    PacketsTransmitted,
+    ErrorsReceived,
+    ErrorsTransmitted,
+    Addresses,
+    Name,
+
+    // TUI
+    Connecting,
+    Offline,
+    RemoteStateUnavailable,
+    RemoteUnresolved,
+    RemoteBadResponse,
+    RemoteNoHosts,
+    Status,
+    Paused,
+    AveragedStatus,
+    SystemResources,
+    CpuInfo,
+    UptimeShort,
+    OverallCpuUsage,
+    Load,
+    CpuTrend,
+    CpuCores,
+    GpuInfo,
+    VramUsage,
+    TemperatureTrend,
+    ThrottleReasons,
+    NoThrottle,
+    ThrottleAppClocks,
+    ThrottleSwPowerCap,
+    ThrottleHwSlowdown,
+    ThrottleSyncBoost,
+    ThrottleSwThermal,
+    ThrottleHwThermal,
+    ThrottleHwPowerBrake,
+    ThrottleDisplayClock,
+    Fan,
+    Power,
+    Committed,
+    ActualUsed,
+    Buffers,
+    MemoryTitle,
+    MemoryTrend,
+    Priority,
+    SwapDevices,
+    DiskDetails,
+    DiskDetailsHint,
+    FileSystem,
+    MountOptions,
+    Capacity,
+    InodeLine,
+    NetworkInterfaces,
+    EntropyPool,
+    NoRaid,
+    RaidArrays,
+    ProcessTitle,
+    SnapshotSaved,
+    SnapshotFailed,
+    HistorySaved,
+    HistoryFailed,
+    Vendor,
+    Family,
+    Model,
+    Cache,
+    CapCpuFrequency,
+    CapCpuTemperature,
+    CapLoadAverage,
+    CapDiskIo,
+    CapNetwork,
+    CapIpAddress,
+    CapCores,
+    CapFrequencyUnavailable,
+    CapNoSensor,
+    CapUnsupported,
+    CapMemoryTotal,
+    CapSwapTotal,
+    CapNoMounts,
+    CapMounts,
+    CapNoInterfaces,
+    CapInterfaces,
+    CapNoAddresses,
+    CapAddresses,
+    CapEstablished,
+    CapProcesses,
+    CapNoGpu,
+    CapRaidArrays,
+    DiskNotResponding,
+    DiskProbeExited,
+    TemplateUnclosed,
+    TemplateStrayBrace,
+    TemplateUnknown,
+    TemplateInvalidSpec,
+    FormatStringHelp,
+    OnelineFormatHelp,
+    FieldCpuTotal,
+    FieldCpuAverage,
+    FieldMemUsedPct,
+    FieldMemUsed,
+    FieldMemTotal,
+    FieldMemAvailable,
+    FieldSwapUsedPct,
+    FieldSwapUsed,
+    FieldSwapTotal,
+    FieldDiskMaxPct,
+    FieldNetRx,
+    FieldNetTx,
+    FieldGpuUtil,
+    FieldGpuMemPct,
+    FieldGpuTemp,
+    FieldHealth,
+    OnelineCpu,
+    OnelineMem,
+    OnelineSwap,
+    OnelineDisk,
+    OnelineNetRx,
+    OnelineNetTx,
+    OnelineGpu,
+    ConfigInvalidColor,
+    ConfigLevelOrder,
+    ConfigUnreadable,
+    ConfigMalformed,
+    ArgInvalidWeight,
+    ArgNegativeWeight,
+    ArgWeightCount,
+    ArgInvalidThreshold,
+    ArgUnknownResource,
+    ArgInvalidSmoothing,
+    ArgSmoothingRange,
+    ArgInvalidPercent,
+    ArgPercentRange,
+    ArgZeroInterval,
+    ArgInvalidDuration,
+    ArgNonFiniteDuration,
+    ArgNegativeDuration,
+    ArgDurationTooLong,
+}
+
+fn zh(text: Text) -> &'static str {
+    match text {
+        Text::Used => "已用",
+        Text::Total => "总计",
+        Text::Available => "可用",
+        Text::Usage => "使用率",
+        Text::Read => "读取",
+        Text::Write => "写入",
+        Text::ReadShort => "读",
+        Text::WriteShort => "写",
+        Text::Receive => "接收",
+        Text::Transmit => "发送",
+        Text::Disabled => "未启用",
+        Text::ReadFailed => "读取失败",
+        Text::ReadOnly => "只读",
+        Text::Removable => "可移动",
+        Text::Yes => "是",
+        Text::No => "否",
+        Text::Unknown => "未知",
+        Text::Memory => "内存",
+        Text::Disk => "磁盘",
+        Text::Swap => "交换分区",
+        Text::SwapShort => "交换",
+        Text::Points => "分",
+        Text::Frequency => "频率",
+        Text::Core => "核心",
+        Text::Process => "进程",
+        Text::Packets => "包",
+        Text::Errors => "错误",
+        Text::NoAddress => "(无地址)",
+        Text::DiskRead => "磁盘读",
+        Text::DiskWrite => "磁盘写",
+        Text::Degraded => "降级",
+        Text::Unavailable => "⚠ 不可用: {}",
+        Text::Healthy => "正常",
+        Text::TcpConnections => "TCP 连接",
+        Text::LocalAddress => "本地地址",
+        Text::RemoteAddress => "远程地址",
+        Text::Established => "已建立",
+        Text::Listen => "监听",
+        Text::Handshake => "握手中",
+
+        Text::Banner => "系统资源监控:",
+        Text::QuitHint => "按 Ctrl+C 退出",
+        Text::BothCollectFailed => "CPU 和内存数据均采集失败",
+        Text::CpuCollectFailed => "CPU 数据采集失败",
+        Text::MemoryCollectFailed => "内存数据采集失败",
+        Text::CpuUsageExceeds => "CPU 使用率 {} 超过阈值 {}",
+        Text::MemoryUsageExceeds => "内存使用率 {} 超过阈值 {}",
+        Text::DiskUsageExceeds => "磁盘 {} 使用率 {} 超过阈值 {}",
+        Text::DiskReadFailed => "磁盘 {} 读取失败: {}",
+        Text::SelfMemoryExceeds => "⚠ litemon 自身内存占用 {} 超过上限 {}",
+        Text::CpuAlert => "CPU 使用率持续偏高: {}",
+        Text::MemoryAlert => "内存使用率持续偏高: {}",
+        Text::DiskAlert => "磁盘 {} 空间即将用尽: 已用 {}",
+        Text::DiskAlertStatus => "磁盘空间告警: {}",
+        Text::Summary => "统计（{} 次采样）:",
+        Text::SummaryStat => "{}: 最低 {}, 最高 {}, 平均 {}",
+        Text::CpuUsage => "CPU 使用率",
+        Text::MemoryUsage => "内存使用率",
+        Text::NetworkPeak => "网络峰值",
+        Text::Uptime => "运行时间: {}（启动于 {}）",
+        Text::HealthScore => "系统健康度",
+        Text::Bottleneck => "瓶颈",
+        Text::CpuModel => "CPU 型号",
+        Text::CpuTotalUsage => "CPU 总使用率",
+        Text::LoadAverage => "平均负载: {}（1/5/15 分钟）",
+        Text::CpuAverageFrequency => "CPU 平均频率",
+        Text::CoreLine => "核心 #{} 使用率: {}, 频率: {}",
+        Text::MemoryUsed => "内存已用",
+        Text::MemoryAvailable => "内存可用",
+        Text::SwapUsed => "交换分区已用",
+        Text::DiskLine => "磁盘 {}{} 已用: {}{}, 读取: {}, 写入: {}",
+        Text::DiskInodes => "磁盘 {} inode 已用: {}",
+        Text::GpuModel => "GPU 型号",
+        Text::GpuUsage => "GPU 使用率",
+        Text::GpuTemperature => "GPU 温度",
+        Text::GpuFan => "GPU 风扇",
+        Text::GpuPower => "GPU 功耗",
+        Text::VramUsed => "显存已用",
+        Text::GpuThrottle => "GPU 降频原因",
+        Text::KernelEntropy => "内核可用熵",
+        Text::RaidLine => "RAID {} ({}): {}, 成员盘 {}",
+
+        Text::Device => "设备",
+        Text::MountPoint => "挂载点",
+        Text::Type => "类型",
+        Text::Interface => "接口",
+        Text::TotalReceived => "总接收",
+        Text::TotalTransmitted => "总发送",
+        Text::PacketsReceived => "收包",
+        Text::PacketsTransmitted => "发包",
+        Text::ErrorsReceived => "接收错误",
+        Text::ErrorsTransmitted => "发送错误",
+        Text::Addresses => "地址",
+        Text::Name => "名称",
+
+        Text::Connecting => "连接中",
+        Text::Offline => "离线",
+        Text::RemoteStateUnavailable => "状态不可用",
+        Text::RemoteUnresolved => "无法解析地址 {}",
+        Text::RemoteBadResponse => "响应格式错误",
+        Text::RemoteNoHosts => "{} 中没有主机",
+        Text::Status => "状态",
+        Text::Paused => "[PAUSED] 按空格继续",
+        Text::AveragedStatus => "[平均值: 最近 {} 次采样]",
+        Text::SystemResources => "系统资源",
+        Text::CpuInfo => "CPU信息",
+        Text::UptimeShort => "运行",
+        Text::OverallCpuUsage => "总体CPU使用率",
+        Text::Load => "负载",
+        Text::CpuTrend => "CPU 走势",
+        Text::CpuCores => "CPU核心状态",
+        Text::GpuInfo => "GPU信息",
+        Text::VramUsage => "显存使用率",
+        Text::TemperatureTrend => "温度走势",
+        Text::ThrottleReasons => "降频原因",
+        Text::NoThrottle => "无",
+        Text::ThrottleAppClocks => "应用时钟设置",
+        Text::ThrottleSwPowerCap => "功率限制",
+        Text::ThrottleHwSlowdown => "硬件降速",
+        Text::ThrottleSyncBoost => "同步加速组",
+        Text::ThrottleSwThermal => "热节流",
+        Text::ThrottleHwThermal => "硬件热节流",
+        Text::ThrottleHwPowerBrake => "电源制动",
+        Text::ThrottleDisplayClock => "显示时钟设置",
+        Text::Fan => "风扇",
+        Text::Power => "功耗",
+        Text::Committed => "已提交",
+        Text::ActualUsed => "实际",
+        Text::Buffers => "缓冲",
+        Text::MemoryTitle => "内存使用情况",
+        Text::MemoryTrend => "内存走势",
+        Text::Priority => "优先级",
+        Text::SwapDevices => "交换设备",
+        Text::DiskDetails => "磁盘详情",
+        Text::DiskDetailsHint => "Esc 返回，↑/↓ 切换",
+        Text::FileSystem => "文件系统",
+        Text::MountOptions => "挂载选项",
+        Text::Capacity => "总容量",
+        Text::InodeLine => "inode: {}，剩余 {}",
+        Text::NetworkInterfaces => "网络接口状态",
+        Text::EntropyPool => "内核熵池",
+        Text::NoRaid => "未检测到 RAID 阵列",
+        Text::RaidArrays => "RAID 阵列",
+        Text::ProcessTitle => "进程 (按{}排序, s 切换)",
+        Text::SnapshotSaved => "快照已保存",
+        Text::SnapshotFailed => "快照保存失败",
+        Text::HistorySaved => "走势历史已导出",
+        Text::HistoryFailed => "走势历史导出失败",
+        Text::Vendor => "厂商",
+        Text::Family => "系列",
+        Text::Model => "型号",
+        Text::Cache => "缓存",
+        Text::CapCpuFrequency => "CPU 频率",
+        Text::CapCpuTemperature => "CPU 温度",
+        Text::CapLoadAverage => "平均负载",
+        Text::CapDiskIo => "磁盘读写速率",
+        Text::CapNetwork => "网络接口",
+        Text::CapIpAddress => "IP 地址",
+        Text::CapCores => "{} 个核心，{}",
+        Text::CapFrequencyUnavailable => "0（不可用）",
+        Text::CapNoSensor => "未找到温度传感器",
+        Text::CapUnsupported => "当前系统不支持",
+        Text::CapMemoryTotal => "总量 {} 字节",
+        Text::CapSwapTotal => "总量 {} 字节，{} 个设备",
+        Text::CapNoMounts => "未找到挂载点",
+        Text::CapMounts => "{} 个挂载点，{} 个读取失败",
+        Text::CapNoInterfaces => "未找到网络接口",
+        Text::CapInterfaces => "{} 个接口",
+        Text::CapNoAddresses => "未找到地址",
+        Text::CapAddresses => "{} 个地址",
+        Text::CapEstablished => "已建立 {}",
+        Text::CapProcesses => "{} 个进程",
+        Text::CapNoGpu => "未找到 GPU（或以 --no-gpu 启动）",
+        Text::CapRaidArrays => "{} 个阵列",
+        Text::DiskNotResponding => "无响应",
+        Text::DiskProbeExited => "探测线程异常退出",
+        Text::TemplateUnclosed => "占位符 '{{}' 缺少右花括号",
+        Text::TemplateStrayBrace => "多余的右花括号，使用 '}}' 输出字面量",
+        Text::TemplateUnknown => "未知占位符 '{}'，可用: {}",
+        Text::TemplateInvalidSpec => "无效的格式说明 ':{}'，应为 [宽度][.精度]",
+        Text::FormatStringHelp => "按自定义模板逐行输出（不启动 TUI），如 \"CPU {cpu.total:.0}% MEM {mem.used_pct:.0}%\"\n占位符可带 :宽度.精度 格式说明，可用占位符：",
+        Text::OnelineFormatHelp => "--oneline 的格式，如 \"CPU {cpu} MEM {mem} ↓{net_rx}\"，可用占位符：",
+        Text::FieldCpuTotal => "CPU 总使用率 (%)",
+        Text::FieldCpuAverage => "CPU 平均使用率 (%)",
+        Text::FieldMemUsedPct => "内存使用率 (%)",
+        Text::FieldMemUsed => "已用内存 (字节)",
+        Text::FieldMemTotal => "内存总量 (字节)",
+        Text::FieldMemAvailable => "可用内存 (字节)",
+        Text::FieldSwapUsedPct => "交换分区使用率 (%)，未启用时为 N/A",
+        Text::FieldSwapUsed => "已用交换分区 (字节)",
+        Text::FieldSwapTotal => "交换分区总量 (字节)",
+        Text::FieldDiskMaxPct => "使用率最高的磁盘 (%)",
+        Text::FieldNetRx => "所有接口接收速率之和 (字节/秒)",
+        Text::FieldNetTx => "所有接口发送速率之和 (字节/秒)",
+        Text::FieldGpuUtil => "GPU 使用率 (%)",
+        Text::FieldGpuMemPct => "显存使用率 (%)",
+        Text::FieldGpuTemp => "GPU 温度 (°C)",
+        Text::FieldHealth => "系统健康分 (0-100)",
+        Text::OnelineCpu => "CPU 总使用率，如 23%",
+        Text::OnelineMem => "内存使用率",
+        Text::OnelineSwap => "交换分区使用率",
+        Text::OnelineDisk => "使用率最高的磁盘",
+        Text::OnelineNetRx => "所有接口接收速率之和，如 1.2M",
+        Text::OnelineNetTx => "所有接口发送速率之和",
+        Text::OnelineGpu => "GPU 使用率",
+        Text::ConfigInvalidColor => "无效的颜色 '{}'",
+        Text::ConfigLevelOrder => "警告分界 {} 不能高于严重分界 {}",
+        Text::ConfigUnreadable => "无法读取 {}: {}",
+        Text::ConfigMalformed => "{} 格式错误: {}",
+        Text::ArgInvalidWeight => "无效的权重 '{}': {}",
+        Text::ArgNegativeWeight => "权重不能为负数",
+        Text::ArgWeightCount => "需要 4 个以逗号分隔的权重：CPU,内存,磁盘,交换分区",
+        Text::ArgInvalidThreshold => "无效的阈值 '{}'，应为 资源=百分比，如 cpu=90",
+        Text::ArgUnknownResource => "未知资源 '{}'，可用: cpu, mem, disk",
+        Text::ArgInvalidSmoothing => "无效的平滑系数 '{}'",
+        Text::ArgSmoothingRange => "平滑系数需大于 0 且不超过 1: '{}'",
+        Text::ArgInvalidPercent => "无效的百分比 '{}'",
+        Text::ArgPercentRange => "百分比需在 0 到 100 之间: '{}'",
+        Text::ArgZeroInterval => "采样间隔必须大于 0: '{}'",
+        Text::ArgInvalidDuration => "无效的时长 '{}'，示例: 500ms、2s、1.5",
+        Text::ArgNonFiniteDuration => "时长必须是有限的数字: '{}'",
+        Text::ArgNegativeDuration => "时长不能为负数: '{}'",
+        Text::ArgDurationTooLong => "时长过长: '{}'",
+    }
+}
+
+fn en(text: Text) -> &'static str {
+    match text {
+        Text::Used => "Used",
+        Text::Total => "Total",
+        Text::Available => "Available",
+        Text::Usage => "Usage",
+        Text::Read => "Read",
+        Text::Write => "Write",
+        Text::ReadShort => "R",
+        Text::WriteShort => "W",
+        Text::Receive => "RX",
+        Text::Transmit => "TX",
+        Text::Disabled => "disabled",
+        Text::ReadFailed => "Read failed",
+        Text::ReadOnly => "Read-only",
+        Text::Removable => "Removable",
+        Text::Yes => "yes",
+        Text::No => "no",
+        Text::Unknown => "unknown",
+        Text::Memory => "Memory",
+        Text::Disk => "Disk",
+        Text::Swap => "Swap",
+        Text::SwapShort => "Swap",
+        Text::Points => "points",
+        Text::Frequency => "Frequency",
+        Text::Core => "Core",
+        Text::Process => "Process",
+        Text::Packets => "Packets",
+        Text::Errors => "Errors",
+        Text::NoAddress => "(no address)",
+        Text::DiskRead => "disk read",
+        Text::DiskWrite => "disk write",
+        Text::Degraded => "degraded",
+        Text::Unavailable => "⚠ unavailable: {}",
+        Text::Healthy => "OK",
+        Text::TcpConnections => "TCP connections",
+        Text::LocalAddress => "Local",
+        Text::RemoteAddress => "Remote",
+        Text::Established => "established",
+        Text::Listen => "listen",
+        Text::Handshake => "handshaking",
+
+        Text::Banner => "System resource monitor:",
+        Text::QuitHint => "Press Ctrl+C to exit",
+        Text::BothCollectFailed => "Failed to collect both CPU and memory data",
+        Text::CpuCollectFailed => "Failed to collect CPU data",
+        Text::MemoryCollectFailed => "Failed to collect memory data",
+        Text::CpuUsageExceeds => "CPU usage {} exceeds threshold {}",
+        Text::MemoryUsageExceeds => "Memory usage {} exceeds threshold {}",
+        Text::DiskUsageExceeds => "Disk {} usage {} exceeds threshold {}",
+        Text::DiskReadFailed => "Disk {} read failed: {}",
+        Text::SelfMemoryExceeds => "⚠ litemon's own memory usage {} exceeds limit {}",
+        Text::CpuAlert => "CPU usage has stayed high: {}",
+        Text::MemoryAlert => "Memory usage has stayed high: {}",
+        Text::DiskAlert => "Disk {} is running out of space: {} used",
+        Text::DiskAlertStatus => "Disk space alert: {}",
+        Text::Summary => "Summary ({} samples):",
+        Text::SummaryStat => "{}: min {}, max {}, avg {}",
+        Text::CpuUsage => "CPU usage",
+        Text::MemoryUsage => "Memory usage",
+        Text::NetworkPeak => "Network peak",
+        Text::Uptime => "Uptime: {} (booted {})",
+        Text::HealthScore => "Health score",
+        Text::Bottleneck => "Bottleneck",
+        Text::CpuModel => "CPU model",
+        Text::CpuTotalUsage => "CPU total usage",
+        Text::LoadAverage => "Load average: {} (1/5/15 min)",
+        Text::CpuAverageFrequency => "CPU average frequency",
+        Text::CoreLine => "Core #{} usage: {}, frequency: {}",
+        Text::MemoryUsed => "Memory used",
+        Text::MemoryAvailable => "Memory available",
+        Text::SwapUsed => "Swap used",
+        Text::DiskLine => "Disk {}{} used: {}{}, read: {}, write: {}",
+        Text::DiskInodes => "Disk {} inodes used: {}",
+        Text::GpuModel => "GPU model",
+        Text::GpuUsage => "GPU usage",
+        Text::GpuTemperature => "GPU temperature",
+        Text::GpuFan => "GPU fan",
+        Text::GpuPower => "GPU power",
+        Text::VramUsed => "VRAM used",
+        Text::GpuThrottle => "GPU throttle reasons",
+        Text::KernelEntropy => "Kernel entropy available",
+        Text::RaidLine => "RAID {} ({}): {}, member disks {}",
+
+        Text::Device => "Device",
+        Text::MountPoint => "Mount point",
+        Text::Type => "Type",
+        Text::Interface => "Interface",
+        Text::TotalReceived => "Total RX",
+        Text::TotalTransmitted => "Total TX",
+        Text::PacketsReceived => "RX packets",
+        Text::PacketsTransmitted => "TX packets",
+        Text::ErrorsReceived => "RX errors",
+        Text::ErrorsTransmitted => "TX errors",
+        Text::Addresses => "Addresses",
+        Text::Name => "Name",
+
+        Text::Connecting => "connecting",
+        Text::Offline => "offline",
+        Text::RemoteStateUnavailable => "state unavailable",
+        Text::RemoteUnresolved => "cannot resolve address {}",
+        Text::RemoteBadResponse => "malformed response",
+        Text::RemoteNoHosts => "no hosts in {}",
+        Text::Status => "Status",
+        Text::Paused => "[PAUSED] press Space to resume",
+        Text::AveragedStatus => "[Average: last {} samples]",
+        Text::SystemResources => "System resources",
+        Text::CpuInfo => "CPU",
+        Text::UptimeShort => "up",
+        Text::OverallCpuUsage => "Overall CPU usage",
+        Text::Load => "load",
+        Text::CpuTrend => "CPU trend",
+        Text::CpuCores => "CPU cores",
+        Text::GpuInfo => "GPU",
+        Text::VramUsage => "VRAM usage",
+        Text::TemperatureTrend => "Temperature trend",
+        Text::ThrottleReasons => "Throttle reasons",
+        Text::NoThrottle => "none",
+        Text::ThrottleAppClocks => "application clocks setting",
+        Text::ThrottleSwPowerCap => "power cap",
+        Text::ThrottleHwSlowdown => "hardware slowdown",
+        Text::ThrottleSyncBoost => "sync boost",
+        Text::ThrottleSwThermal => "thermal slowdown",
+        Text::ThrottleHwThermal => "hardware thermal slowdown",
+        Text::ThrottleHwPowerBrake => "power brake",
+        Text::ThrottleDisplayClock => "display clock setting",
+        Text::Fan => "fan",
+        Text::Power => "power",
+        Text::Committed => "Committed",
+        Text::ActualUsed => "Actual",
+        Text::Buffers => "Buffers",
+        Text::MemoryTitle => "Memory",
+        Text::MemoryTrend => "Memory trend",
+        Text::Priority => "priority",
+        Text::SwapDevices => "Swap devices",
+        Text::DiskDetails => "Disk details",
+        Text::DiskDetailsHint => "Esc to return, ↑/↓ to switch",
+        Text::FileSystem => "File system",
+        Text::MountOptions => "Mount options",
+        Text::Capacity => "Capacity",
+        Text::InodeLine => "inodes: {}, {} free",
+        Text::NetworkInterfaces => "Network interfaces",
+        Text::EntropyPool => "Kernel entropy pool",
+        Text::NoRaid => "No RAID arrays detected",
+        Text::RaidArrays => "RAID arrays",
+        Text::ProcessTitle => "Processes (sorted by {}, s to change)",
+        Text::SnapshotSaved => "Snapshot saved",
+        Text::SnapshotFailed => "Failed to save snapshot",
+        Text::HistorySaved => "History exported",
+        Text::HistoryFailed => "Failed to export history",
+        Text::Vendor => "Vendor",
+        Text::Family => "Family",
+        Text::Model => "Model",
+        Text::Cache => "Cache",
+        Text::CapCpuFrequency => "CPU frequency",
+        Text::CapCpuTemperature => "CPU temperature",
+        Text::CapLoadAverage => "Load average",
+        Text::CapDiskIo => "Disk I/O rate",
+        Text::CapNetwork => "Network interfaces",
+        Text::CapIpAddress => "IP addresses",
+        Text::CapCores => "{} cores, {}",
+        Text::CapFrequencyUnavailable => "0 (unavailable)",
+        Text::CapNoSensor => "no temperature sensor found",
+        Text::CapUnsupported => "not supported on this platform",
+        Text::CapMemoryTotal => "{} bytes total",
+        Text::CapSwapTotal => "{} bytes total, {} devices",
+        Text::CapNoMounts => "no mount points found",
+        Text::CapMounts => "{} mount points, {} unreadable",
+        Text::CapNoInterfaces => "no network interfaces found",
+        Text::CapInterfaces => "{} interfaces",
+        Text::CapNoAddresses => "no addresses found",
+        Text::CapAddresses => "{} addresses",
+        Text::CapEstablished => "{} established",
+        Text::CapProcesses => "{} processes",
+        Text::CapNoGpu => "no GPU found (or started with --no-gpu)",
+        Text::CapRaidArrays => "{} arrays",
+        Text::DiskNotResponding => "not responding",
+        Text::DiskProbeExited => "probe thread exited unexpectedly",
+        Text::TemplateUnclosed => "placeholder '{{}' is missing a closing brace",
+        Text::TemplateStrayBrace => "unmatched closing brace, use '}}' for a literal brace",
+        Text::TemplateUnknown => "unknown placeholder '{}', available: {}",
+        Text::TemplateInvalidSpec => "invalid format spec ':{}', expected [width][.precision]",
+        Text::FormatStringHelp => "print one line per refresh from a template (no TUI), e.g. \"CPU {cpu.total:.0}% MEM {mem.used_pct:.0}%\"\nplaceholders accept a :width.precision spec; available placeholders:",
+        Text::OnelineFormatHelp => "format for --oneline, e.g. \"CPU {cpu} MEM {mem} ↓{net_rx}\"; available placeholders:",
+        Text::FieldCpuTotal => "total CPU usage (%)",
+        Text::FieldCpuAverage => "average CPU usage (%)",
+        Text::FieldMemUsedPct => "memory usage (%)",
+        Text::FieldMemUsed => "used memory (bytes)",
+        Text::FieldMemTotal => "total memory (bytes)",
+        Text::FieldMemAvailable => "available memory (bytes)",
+        Text::FieldSwapUsedPct => "swap usage (%), N/A when swap is disabled",
+        Text::FieldSwapUsed => "used swap (bytes)",
+        Text::FieldSwapTotal => "total swap (bytes)",
+        Text::FieldDiskMaxPct => "usage of the fullest disk (%)",
+        Text::FieldNetRx => "combined receive rate of all interfaces (bytes/s)",
+        Text::FieldNetTx => "combined transmit rate of all interfaces (bytes/s)",
+        Text::FieldGpuUtil => "GPU utilization (%)",
+        Text::FieldGpuMemPct => "GPU memory usage (%)",
+        Text::FieldGpuTemp => "GPU temperature (°C)",
+        Text::FieldHealth => "system health score (0-100)",
+        Text::OnelineCpu => "total CPU usage, e.g. 23%",
+        Text::OnelineMem => "memory usage",
+        Text::OnelineSwap => "swap usage",
+        Text::OnelineDisk => "usage of the fullest disk",
+        Text::OnelineNetRx => "combined receive rate of all interfaces, e.g. 1.2M",
+        Text::OnelineNetTx => "combined transmit rate of all interfaces",
+        Text::OnelineGpu => "GPU utilization",
+        Text::ConfigInvalidColor => "invalid color '{}'",
+        Text::ConfigLevelOrder => "warning level {} cannot be higher than critical level {}",
+        Text::ConfigUnreadable => "cannot read {}: {}",
+        Text::ConfigMalformed => "{} is malformed: {}",
+        Text::ArgInvalidWeight => "invalid weight '{}': {}",
+        Text::ArgNegativeWeight => "weights cannot be negative",
+        Text::ArgWeightCount => "expected 4 comma-separated weights: cpu,memory,disk,swap",
+        Text::ArgInvalidThreshold => "invalid threshold '{}', expected resource=percent, e.g. cpu=90",
+        Text::ArgUnknownResource => "unknown resource '{}', available: cpu, mem, disk",
+        Text::ArgInvalidSmoothing => "invalid smoothing factor '{}'",
+        Text::ArgSmoothingRange => "smoothing factor must be greater than 0 and at most 1: '{}'",
+        Text::ArgInvalidPercent => "invalid percentage '{}'",
+        Text::ArgPercentRange => "percentage must be between 0 and 100: '{}'",
+        Text::ArgZeroInterval => "interval must be greater than 0: '{}'",
+        Text::ArgInvalidDuration => "invalid duration '{}', e.g. 500ms, 2s, 1.5",
+        Text::ArgNonFiniteDuration => "duration must be a finite number: '{}'",
+        Text::ArgNegativeDuration => "duration cannot be negative: '{}'",
+        Text::ArgDurationTooLong => "duration is too long: '{}'",
+    }
+}
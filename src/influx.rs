@@ -0,0 +1,219 @@
+use std::time::UNIX_EPOCH;
+use crate::monitor::{Sample, disk::DiskMonitor};
+
+// 一个数据点：measurement、标签和字段，没有字段时不输出
+struct Point {
+    head: String,
+    fields: Vec<String>,
+}
+
+impl Point {
+    fn new(measurement: &str, host: Option<&str>, tags: &[(&str, &str)]) -> Self {
+        let mut head = escape(measurement, false);
+        for (key, value) in host.map(|host| ("host", host)).iter().chain(tags) {
+            // 行协议不允许空的标签值
+            let value = escape(value, true);
+            if !value.is_empty() {
+                head.push_str(&format!(",{}={}", escape(key, true), value));
+            }
+        }
+        Self { head, fields: Vec::new() }
+    }
+
+    // 行协议不支持 NaN 和无穷大，这类读数直接省略
+    fn float(mut self, key: &str, value: f64) -> Self {
+        if value.is_finite() {
+            self.fields.push(format!("{}={}", escape(key, true), value));
+        }
+        self
+    }
+
+    fn int(mut self, key: &str, value: u64) -> Self {
+        self.fields.push(format!("{}={}i", escape(key, true), value));
+        self
+    }
+
+    fn write(self, out: &mut String, timestamp: u128) {
+        if !self.fields.is_empty() {
+            out.push_str(&format!("{} {} {}\n", self.head, self.fields.join(","), timestamp));
+        }
+    }
+}
+
+// measurement 需转义反斜杠、逗号和空格，标签键、标签值和字段键还需转义等号。
+// 换行会截断整行，无法转义，直接去掉（如 Windows 挂载点 C:\ 转义为 C:\\）
+fn escape(value: &str, equals: bool) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars().filter(|c| !matches!(c, '\n' | '\r')) {
+        if matches!(c, '\\' | ',' | ' ') || (equals && c == '=') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+// f32 读数按其最短十进制表示转换，避免输出 23.100000381469727 这样的尾数
+fn float(value: f32) -> f64 {
+    value.to_string().parse().unwrap_or(f64::NAN)
+}
+
+/// 将一次采样渲染为 InfluxDB 行协议，每个 measurement 一行，时间戳为纳秒。
+/// host 为主机名标签，无法获取时省略；per_core 时额外输出各核心的 cpu 行（带 core 标签）
+pub fn render(sample: &Sample, host: Option<&str>, per_core: bool) -> String {
+    let mut out = String::new();
+    let timestamp = sample.timestamp.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos());
+
+    if let Some(cpu) = &sample.cpu {
+        let mut point = Point::new("cpu", host, &[]).float("usage", float(cpu.total_usage));
+        if let Some(b) = &cpu.breakdown {
+            for (key, value) in [
+                ("user", b.user), ("nice", b.nice), ("system", b.system),
+                ("idle", b.idle), ("iowait", b.iowait), ("irq", b.irq),
+            ] {
+                point = point.float(key, float(value));
+            }
+        }
+        if let Some(temperature) = cpu.temperature {
+            point = point.float("temperature", float(temperature));
+        }
+        point.write(&mut out, timestamp);
+        if per_core {
+            for (i, usage) in cpu.core_usage.iter().enumerate() {
+                Point::new("cpu", host, &[("core", &i.to_string())])
+                    .float("usage", float(*usage))
+                    .int("frequency", cpu.frequency.get(i).copied().unwrap_or(0))
+                    .write(&mut out, timestamp);
+            }
+        }
+    }
+
+    let mut system = Point::new("system", host, &[]).int("uptime", sample.uptime);
+    if let Some(load) = &sample.load_average {
+        system = system.float("load1", load.one).float("load5", load.five).float("load15", load.fifteen);
+    }
+    system.write(&mut out, timestamp);
+
+    if let Some(mem) = &sample.memory {
+        Point::new("mem", host, &[])
+            .int("total", mem.total)
+            .int("used", mem.used)
+            .int("available", mem.available)
+            .float("used_percent", DiskMonitor::usage_percentage(mem.total, mem.used))
+            .write(&mut out, timestamp);
+        Point::new("swap", host, &[])
+            .int("total", mem.swap_total)
+            .int("used", mem.swap_used)
+            .write(&mut out, timestamp);
+    }
+
+    for disk in sample.disks.iter().filter(|disk| disk.error.is_none()) {
+        Point::new("disk", host, &[("device", &disk.name), ("mount", &disk.mount_point), ("fstype", &disk.file_system)])
+            .int("total", disk.total_space)
+            .int("used", disk.used_space)
+            .int("available", disk.available_space)
+            .float("used_percent", DiskMonitor::usage_percentage(disk.total_space, disk.used_space))
+            .int("read_rate", disk.read_bytes_per_sec)
+            .int("write_rate", disk.write_bytes_per_sec)
+            .write(&mut out, timestamp);
+    }
+
+    for net in &sample.networks {
+        Point::new("net", host, &[("interface", &net.interface_name)])
+            .int("bytes_recv", net.total_received)
+            .int("bytes_sent", net.total_transmitted)
+            .int("recv_rate", net.received_bytes)
+            .int("sent_rate", net.transmitted_bytes)
+            .int("packets_recv", net.packets_received)
+            .int("packets_sent", net.packets_transmitted)
+            .int("err_in", net.errors_on_received)
+            .int("err_out", net.errors_on_transmitted)
+            .write(&mut out, timestamp);
+    }
+
+    if let Some(tcp) = &sample.tcp {
+        Point::new("tcp", host, &[])
+            .int("established", tcp.established as u64)
+            .int("listen", tcp.listen as u64)
+            .int("syn", tcp.syn as u64)
+            .int("time_wait", tcp.time_wait as u64)
+            .int("close_wait", tcp.close_wait as u64)
+            .int("other", tcp.other as u64)
+            .write(&mut out, timestamp);
+    }
+
+    if let Some(gpu) = &sample.gpu {
+        let mut point = Point::new("gpu", host, &[("name", &gpu.name)])
+            .int("utilization", gpu.utilization as u64)
+            .int("memory_used", gpu.memory_used)
+            .int("memory_total", gpu.memory_total)
+            .int("temperature", gpu.temperature as u64);
+        if let Some(fan) = gpu.fan_speed_percent {
+            point = point.int("fan_speed", fan as u64);
+        }
+        if let Some(power) = gpu.power_usage_watts {
+            point = point.int("power", power as u64);
+        }
+        point.write(&mut out, timestamp);
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitor::connections::TcpSummary;
+    use std::time::{Duration, SystemTime};
+
+    fn sample() -> Sample {
+        let mut sample: Sample = serde_json::from_str(r#"{
+            "timestamp": 1700000000000, "uptime": 60, "cpu_info": {"brand": "", "vendor": "", "frequency": 0}, "cpu": null, "load_average": null, "memory": null,
+            "disks": [], "networks": [], "tcp": null, "gpu": null, "health": null
+        }"#).unwrap();
+        sample.timestamp = SystemTime::UNIX_EPOCH + Duration::from_nanos(1_700_000_000_123_456_789);
+        sample
+    }
+
+    #[test]
+    fn escapes_tag_values() {
+        assert_eq!(escape("my host", true), "my\\ host");
+        assert_eq!(escape("a,b=c", true), "a\\,b\\=c");
+        assert_eq!(escape("a=b", false), "a=b");
+        assert_eq!(escape("C:\\", true), "C:\\\\");
+        assert_eq!(escape("two\r\nlines", true), "twolines");
+    }
+
+    #[test]
+    fn windows_mount_keeps_following_tags() {
+        let line = Point::new("disk", None, &[("mount", "C:\\"), ("fstype", "NTFS")]).int("used", 1);
+        let mut out = String::new();
+        line.write(&mut out, 0);
+        assert_eq!(out, "disk,mount=C:\\\\,fstype=NTFS used=1i 0\n");
+    }
+
+    #[test]
+    fn host_tag_and_nanosecond_timestamp() {
+        let mut sample = sample();
+        sample.tcp = Some(TcpSummary { established: 3, ..Default::default() });
+        assert_eq!(
+            render(&sample, Some("web 1"), false),
+            "system,host=web\\ 1 uptime=60i 1700000000123456789\n\
+             tcp,host=web\\ 1 established=3i,listen=0i,syn=0i,time_wait=0i,close_wait=0i,other=0i 1700000000123456789\n",
+        );
+    }
+
+    #[test]
+    fn empty_tags_and_non_finite_fields_are_dropped() {
+        let mut out = String::new();
+        Point::new("cpu", Some(""), &[("core", "\n")])
+            .float("usage", f64::NAN)
+            .float("idle", 50.0)
+            .write(&mut out, 1);
+        assert_eq!(out, "cpu idle=50 1\n");
+
+        let mut out = String::new();
+        Point::new("cpu", None, &[]).float("usage", f64::INFINITY).write(&mut out, 1);
+        assert_eq!(out, "");
+    }
+}
@@ -0,0 +1,60 @@
+//! litemon 的采集部分可以作为库使用，不依赖 TUI：
+//!
+//! ```
+//! use litemon::Monitor;
+//!
+//! let mut monitor = Monitor::without_gpu();
+//! monitor.warmup(Monitor::MINIMUM_WARMUP);
+//! let cpu = monitor.cpu_stats()?;
+//! println!("CPU {:.1}%，{} 个核心", cpu.total_usage, cpu.core_count);
+//! # Ok::<(), litemon::LiteMonError>(())
+//! ```
+//!
+//! 每个刷新周期调用一次 [`Monitor::refresh`]，再读取各项数据，
+//! 或用 [`Monitor::sample`] 一次取得完整的 [`Sample`]。
+
+pub mod error;
+pub mod i18n;
+pub mod monitor;
+
+// 以下模块只供 litemon 可执行文件使用，不属于稳定的库接口
+#[doc(hidden)]
+pub mod alert;
+#[doc(hidden)]
+pub mod app;
+#[doc(hidden)]
+pub mod change;
+#[doc(hidden)]
+pub mod cli;
+#[doc(hidden)]
+pub mod exec;
+#[doc(hidden)]
+pub mod exporter;
+#[doc(hidden)]
+pub mod influx;
+#[doc(hidden)]
+pub mod oneline;
+#[doc(hidden)]
+pub mod recorder;
+#[doc(hidden)]
+pub mod remote;
+#[doc(hidden)]
+pub mod summary;
+mod template;
+mod ui;
+mod util;
+
+pub use error::{LiteMonError, Result};
+pub use monitor::{Monitor, RefreshSpec, Sample, Subsystem, FAILURE_THRESHOLD, SCHEMA_VERSION};
+pub use monitor::capabilities::{Capabilities, Capability};
+pub use monitor::clock::{Clock, ManualClock, SystemClock};
+pub use monitor::cpu::{CpuBreakdown, CpuInfo, CpuStats, LoadAverage};
+pub use monitor::memory::{MemoryStats, SwapDevice};
+pub use monitor::disk::{DiskKind, DiskStats, InodeStats};
+pub use monitor::network::NetworkStats;
+pub use monitor::connections::{ConnectionStats, TcpSummary};
+pub use monitor::entropy::EntropyStats;
+pub use monitor::gpu::GpuStats;
+pub use monitor::health::{HealthScore, HealthWeights};
+pub use monitor::process::ProcessStats;
+pub use monitor::raid::RaidStatus;
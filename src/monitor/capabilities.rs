@@ -0,0 +1,35 @@
+use std::fmt;
+
+/// 单个子系统的探测结果：能取得数据时 detail 为读数摘要，否则为原因
+#[derive(Debug, Clone)]
+pub struct Capability {
+    pub name: &'static str,
+    pub available: bool,
+    pub detail: String,
+}
+
+/// 各子系统在当前平台上能否采集到数据，用于区分“平台不支持”和“采集出错”
+#[derive(Debug, Clone, Default)]
+pub struct Capabilities {
+    pub items: Vec<Capability>,
+}
+
+impl Capabilities {
+    pub fn check(&mut self, name: &'static str, result: std::result::Result<String, String>) {
+        let (available, detail) = match result {
+            Ok(detail) => (true, detail),
+            Err(reason) => (false, reason),
+        };
+        self.items.push(Capability { name, available, detail });
+    }
+}
+
+impl fmt::Display for Capabilities {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for item in &self.items {
+            let mark = if item.available { "✓" } else { "✗" };
+            writeln!(f, "{} {}: {}", mark, item.name, item.detail)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 单调时钟来源，供网络速率和 GPU 缓存的计时使用。
+/// 默认为系统时钟；嵌入方可以换成 [`ManualClock`]，按需推进时间来驱动计时逻辑
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Instant;
+}
+
+/// 系统单调时钟
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// 只在调用 advance 时前进的时钟
+#[derive(Debug)]
+pub struct ManualClock {
+    now: Mutex<Instant>,
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self { now: Mutex::new(Instant::now()) }
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        if let Ok(mut now) = self.now.lock() {
+            *now += duration;
+        }
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.now.lock().map_or_else(|e| *e.into_inner(), |now| *now)
+    }
+}
@@ -0,0 +1,365 @@
+use serde::{Deserialize, Serialize};
+use crate::error::Result;
+#[cfg(target_os = "linux")]
+use std::collections::HashMap;
+#[cfg(any(target_os = "linux", windows))]
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+/// 按状态统计的 TCP 连接数（IPv4 + IPv6）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TcpSummary {
+    pub established: usize,
+    pub listen: usize,
+    pub syn: usize,         // SYN_SENT + SYN_RECV
+    pub time_wait: usize,
+    pub close_wait: usize,
+    pub other: usize,
+}
+
+/// 单条 TCP 连接（--connections），地址为 IP:端口，IPv6 地址带方括号
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionStats {
+    pub local: String,
+    pub remote: String,
+    pub state: String,  // 如 ESTABLISHED、LISTEN、TIME_WAIT
+    /// 所属进程，无权查看其他用户的进程时为 None
+    pub pid: Option<u32>,
+    /// 进程名，由 Monitor 按 pid 从进程表中补全
+    #[serde(default)]
+    pub process: Option<String>,
+}
+
+impl ConnectionStats {
+    // 已建立的连接在前，监听套接字在后，其余状态居中；同状态按远程地址排序
+    fn order(&self) -> (u8, &str, &str) {
+        let rank = match self.state.as_str() {
+            "ESTABLISHED" => 0,
+            "LISTEN" => 2,
+            _ => 1,
+        };
+        (rank, &self.remote, &self.local)
+    }
+}
+
+// 每隔多少次采集重新扫描 /proc/<pid>/fd；新连接的所属进程最多晚这么多个周期显示
+#[cfg(target_os = "linux")]
+const OWNER_REFRESH: u32 = 5;
+
+pub struct ConnectionMonitor {
+    #[cfg(target_os = "linux")]
+    owners: HashMap<u64, u32>,  // 套接字 inode -> pid，扫描所有进程的 fd 开销较大，按周期缓存
+    #[cfg(target_os = "linux")]
+    owners_age: u32,  // 缓存建立后经过的采集次数
+}
+
+impl Default for ConnectionMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConnectionMonitor {
+    pub fn new() -> Self {
+        Self {
+            #[cfg(target_os = "linux")]
+            owners: HashMap::new(),
+            #[cfg(target_os = "linux")]
+            owners_age: OWNER_REFRESH,
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn collect_summary(&self) -> Result<TcpSummary> {
+        let mut summary = TcpSummary::default();
+        let mut found = false;
+
+        for path in ["/proc/net/tcp", "/proc/net/tcp6"] {
+            // 未启用 IPv6 时 tcp6 不存在
+            let Ok(content) = std::fs::read_to_string(path) else {
+                continue;
+            };
+            found = true;
+
+            // 第 4 列 "st" 为十六进制状态码
+            for state in content.lines().skip(1).filter_map(|line| line.split_whitespace().nth(3)) {
+                match u8::from_str_radix(state, 16) {
+                    Ok(0x01) => summary.established += 1,
+                    Ok(0x02) | Ok(0x03) => summary.syn += 1,
+                    Ok(0x06) => summary.time_wait += 1,
+                    Ok(0x08) => summary.close_wait += 1,
+                    Ok(0x0A) => summary.listen += 1,
+                    _ => summary.other += 1,
+                }
+            }
+        }
+
+        if !found {
+            return Err(std::io::Error::from(std::io::ErrorKind::NotFound).into());
+        }
+        Ok(summary)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn collect_summary(&self) -> Result<TcpSummary> {
+        Err(std::io::Error::from(std::io::ErrorKind::Unsupported).into())
+    }
+
+    /// 全部 TCP 连接（IPv4 + IPv6），已建立的在前
+    pub fn collect_connections(&mut self) -> Result<Vec<ConnectionStats>> {
+        let mut connections = self.read_connections()?;
+        connections.sort_by(|a, b| a.order().cmp(&b.order()));
+        Ok(connections)
+    }
+
+    // 解析 /proc/net/tcp 和 tcp6：第 2、3 列为十六进制的 地址:端口，第 4 列为状态，第 10 列为套接字 inode。
+    // 进程由 /proc/<pid>/fd 中指向 socket:[inode] 的链接确定
+    #[cfg(target_os = "linux")]
+    fn read_connections(&mut self) -> Result<Vec<ConnectionStats>> {
+        let mut rows = Vec::new();
+        let mut found = false;
+        for path in ["/proc/net/tcp", "/proc/net/tcp6"] {
+            let Ok(content) = std::fs::read_to_string(path) else {
+                continue;
+            };
+            found = true;
+            for line in content.lines().skip(1) {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                let (Some(local), Some(remote), Some(state), Some(inode)) = (
+                    fields.get(1).and_then(|f| Self::parse_proc_addr(f)),
+                    fields.get(2).and_then(|f| Self::parse_proc_addr(f)),
+                    fields.get(3).and_then(|f| u8::from_str_radix(f, 16).ok()),
+                    fields.get(9).and_then(|f| f.parse::<u64>().ok()),
+                ) else {
+                    continue;
+                };
+                rows.push((local, remote, state, inode));
+            }
+        }
+        if !found {
+            return Err(std::io::Error::from(std::io::ErrorKind::NotFound).into());
+        }
+
+        let owners = self.socket_owners();
+        Ok(rows.into_iter()
+            .map(|(local, remote, state, inode)| ConnectionStats {
+                local: local.to_string(),
+                remote: remote.to_string(),
+                state: Self::state_name(state).to_string(),
+                // TIME_WAIT 等已无套接字的连接 inode 为 0，查不到所属进程
+                pid: owners.get(&inode).copied(),
+                process: None,
+            })
+            .collect())
+    }
+
+    // 内核以 %08X 逐个输出地址的 32 位字（主机字节序），还原为网络字节序需按本机字节序取出各字；
+    // IPv4 为 1 个字，IPv6 为 4 个字。端口为普通的十六进制数
+    #[cfg(target_os = "linux")]
+    fn parse_proc_addr(field: &str) -> Option<SocketAddr> {
+        let (addr, port) = field.split_once(':')?;
+        let port = u16::from_str_radix(port, 16).ok()?;
+        let mut bytes = Vec::with_capacity(16);
+        for i in (0..addr.len()).step_by(8) {
+            let word = u32::from_str_radix(addr.get(i..i + 8)?, 16).ok()?;
+            bytes.extend_from_slice(&word.to_ne_bytes());
+        }
+        let ip = match bytes.len() {
+            4 => IpAddr::V4(Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3])),
+            16 => IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(bytes).ok()?)),
+            _ => return None,
+        };
+        Some(SocketAddr::new(ip, port))
+    }
+
+    // include/net/tcp_states.h
+    #[cfg(target_os = "linux")]
+    fn state_name(state: u8) -> &'static str {
+        match state {
+            0x01 => "ESTABLISHED",
+            0x02 => "SYN_SENT",
+            0x03 => "SYN_RECV",
+            0x04 => "FIN_WAIT1",
+            0x05 => "FIN_WAIT2",
+            0x06 => "TIME_WAIT",
+            0x07 => "CLOSE",
+            0x08 => "CLOSE_WAIT",
+            0x09 => "LAST_ACK",
+            0x0A => "LISTEN",
+            0x0B => "CLOSING",
+            _ => "UNKNOWN",
+        }
+    }
+
+    // 缓存的套接字所属进程，每 OWNER_REFRESH 次采集重新扫描一次
+    #[cfg(target_os = "linux")]
+    fn socket_owners(&mut self) -> &HashMap<u64, u32> {
+        if self.owners_age >= OWNER_REFRESH {
+            self.owners = Self::scan_socket_owners();
+            self.owners_age = 0;
+        }
+        self.owners_age += 1;
+        &self.owners
+    }
+
+    // 套接字 inode 到进程 pid 的映射；无权读取的进程跳过
+    #[cfg(target_os = "linux")]
+    fn scan_socket_owners() -> HashMap<u64, u32> {
+        let mut owners = HashMap::new();
+        let Ok(entries) = std::fs::read_dir("/proc") else {
+            return owners;
+        };
+        for entry in entries.flatten() {
+            let Some(pid) = entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else {
+                continue;
+            };
+            let Ok(fds) = std::fs::read_dir(entry.path().join("fd")) else {
+                continue;
+            };
+            for fd in fds.flatten() {
+                let Ok(target) = std::fs::read_link(fd.path()) else {
+                    continue;
+                };
+                let inode = target.to_str()
+                    .and_then(|target| target.strip_prefix("socket:[")?.strip_suffix(']')?.parse::<u64>().ok());
+                if let Some(inode) = inode {
+                    owners.entry(inode).or_insert(pid);
+                }
+            }
+        }
+        owners
+    }
+
+    // GetExtendedTcpTable 的 TCP_TABLE_OWNER_PID_ALL 表自带所属进程
+    #[cfg(windows)]
+    fn read_connections(&mut self) -> Result<Vec<ConnectionStats>> {
+        use winapi::shared::tcpmib::{MIB_TCP6TABLE_OWNER_PID, MIB_TCPTABLE_OWNER_PID};
+        use winapi::shared::ws2def::{AF_INET, AF_INET6};
+
+        let mut connections = Vec::new();
+        if let Some(buffer) = Self::owner_pid_table(AF_INET as u32) {
+            // 缓冲区由系统按表结构填充，按 u32 分配保证对齐
+            let table = unsafe { &*(buffer.as_ptr() as *const MIB_TCPTABLE_OWNER_PID) };
+            let rows = unsafe { std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize) };
+            for row in rows {
+                // 地址和端口均为网络字节序，端口只占低 16 位
+                let address = |addr: u32, port: u32| SocketAddr::new(
+                    IpAddr::V4(Ipv4Addr::from(addr.to_ne_bytes())),
+                    u16::from_be(port as u16),
+                );
+                connections.push(ConnectionStats {
+                    local: address(row.dwLocalAddr, row.dwLocalPort).to_string(),
+                    remote: address(row.dwRemoteAddr, row.dwRemotePort).to_string(),
+                    state: Self::windows_state_name(row.dwState).to_string(),
+                    pid: Some(row.dwOwningPid),
+                    process: None,
+                });
+            }
+        }
+        if let Some(buffer) = Self::owner_pid_table(AF_INET6 as u32) {
+            let table = unsafe { &*(buffer.as_ptr() as *const MIB_TCP6TABLE_OWNER_PID) };
+            let rows = unsafe { std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize) };
+            for row in rows {
+                let address = |addr: [u8; 16], port: u32| SocketAddr::new(
+                    IpAddr::V6(Ipv6Addr::from(addr)),
+                    u16::from_be(port as u16),
+                );
+                connections.push(ConnectionStats {
+                    local: address(row.ucLocalAddr, row.dwLocalPort).to_string(),
+                    remote: address(row.ucRemoteAddr, row.dwRemotePort).to_string(),
+                    state: Self::windows_state_name(row.dwState).to_string(),
+                    pid: Some(row.dwOwningPid),
+                    process: None,
+                });
+            }
+        }
+        Ok(connections)
+    }
+
+    // 先以空缓冲区查询所需大小；两次调用之间连接数可能增加，此时按新的大小重试
+    #[cfg(windows)]
+    fn owner_pid_table(family: u32) -> Option<Vec<u32>> {
+        use winapi::shared::iprtrmib::TCP_TABLE_OWNER_PID_ALL;
+        use winapi::shared::winerror::{ERROR_INSUFFICIENT_BUFFER, NO_ERROR};
+        use winapi::um::iphlpapi::GetExtendedTcpTable;
+
+        let mut size = 0u32;
+        for _ in 0..3 {
+            let mut buffer = vec![0u32; (size as usize).div_ceil(4)];
+            let result = unsafe {
+                GetExtendedTcpTable(buffer.as_mut_ptr().cast(), &mut size, 0, family, TCP_TABLE_OWNER_PID_ALL, 0)
+            };
+            match result {
+                NO_ERROR => return Some(buffer),
+                ERROR_INSUFFICIENT_BUFFER => continue,
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    // MIB_TCP_STATE，名称与 Linux 保持一致
+    #[cfg(windows)]
+    fn windows_state_name(state: u32) -> &'static str {
+        match state {
+            1 => "CLOSE",
+            2 => "LISTEN",
+            3 => "SYN_SENT",
+            4 => "SYN_RECV",
+            5 => "ESTABLISHED",
+            6 => "FIN_WAIT1",
+            7 => "FIN_WAIT2",
+            8 => "CLOSE_WAIT",
+            9 => "CLOSING",
+            10 => "LAST_ACK",
+            11 => "TIME_WAIT",
+            _ => "UNKNOWN",
+        }
+    }
+
+    #[cfg(not(any(target_os = "linux", windows)))]
+    fn read_connections(&mut self) -> Result<Vec<ConnectionStats>> {
+        Err(std::io::Error::from(std::io::ErrorKind::Unsupported).into())
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    #[test]
+    fn parses_proc_addresses() {
+        let v4 = if cfg!(target_endian = "little") { "0100007F:1F90" } else { "7F000001:1F90" };
+        assert_eq!(ConnectionMonitor::parse_proc_addr(v4), Some("127.0.0.1:8080".parse().unwrap()));
+        assert_eq!(ConnectionMonitor::parse_proc_addr("00000000000000000000000000000000:0016"), Some("[::]:22".parse().unwrap()));
+        assert_eq!(ConnectionMonitor::parse_proc_addr("0100007F"), None);
+    }
+
+    #[test]
+    fn own_listener_has_owner() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let local = listener.local_addr().unwrap().to_string();
+        let mut monitor = ConnectionMonitor::new();
+        let connections = monitor.collect_connections().unwrap();
+        let own = connections.iter().find(|connection| connection.local == local).unwrap();
+        assert_eq!(own.state, "LISTEN");
+        assert_eq!(own.pid, Some(std::process::id()));
+    }
+
+    #[test]
+    fn owners_are_rescanned_periodically() {
+        let mut monitor = ConnectionMonitor::new();
+        monitor.socket_owners();
+        // 缓存期内新建的套接字要等到下次扫描才有所属进程
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let local = listener.local_addr().unwrap().to_string();
+        let owner = |monitor: &mut ConnectionMonitor| {
+            let connections = monitor.collect_connections().unwrap();
+            connections.into_iter().find(|connection| connection.local == local).unwrap().pid
+        };
+        for _ in 1..OWNER_REFRESH {
+            assert_eq!(owner(&mut monitor), None);
+        }
+        assert_eq!(owner(&mut monitor), Some(std::process::id()));
+    }
+}
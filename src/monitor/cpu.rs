@@ -1,23 +1,313 @@
-use sysinfo::{CpuExt, System, SystemExt};
+use sysinfo::{ComponentExt, CpuExt, LoadAvg, System, SystemExt};
+use serde::{Deserialize, Serialize};
 use crate::error::Result;
+use std::collections::VecDeque;
+use std::time::SystemTime;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CpuStats {
     pub total_usage: f32,
+    /// JSON 输出未加 --per-core 时省略各核心数组，读取时按空处理
+    #[serde(default)]
     pub core_usage: Vec<f32>,
     pub core_count: usize,
+    #[serde(default)]
     pub frequency: Vec<u64>,
+    /// 各核心的最高频率（MHz），启动时读取一次，无法获取时为 0
+    #[serde(default)]
+    pub max_frequency: Vec<u64>,
+    /// 最近若干次采样的平均值，与瞬时值并行维护
+    pub average_usage: f32,
+    #[serde(default)]
+    pub core_average: Vec<f32>,
+    /// CPU 温度（°C），系统未暴露传感器时为 None
+    pub temperature: Option<f32>,
+    /// 按时间类别划分的 CPU 时间占比（仅 Linux，读取 /proc/stat）
+    #[serde(default)]
+    pub breakdown: Option<CpuBreakdown>,
+    #[serde(serialize_with = "super::serialize_timestamp", deserialize_with = "super::deserialize_timestamp")]
+    pub timestamp: SystemTime,  // 采样时刻
+}
+
+/// 两次采样之间各类 CPU 时间的百分比，所有核心合计。
+/// irq 包含软中断；虚拟化环境中被宿主机占用的 steal 时间只计入分母，因此各项之和可能小于 100
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct CpuBreakdown {
+    pub user: f32,
+    pub nice: f32,
+    pub system: f32,
+    pub idle: f32,
+    pub iowait: f32,
+    pub irq: f32,
+}
+
+// /proc/stat 第一行的累计时钟节拍：user nice system idle iowait irq softirq steal
+#[cfg(target_os = "linux")]
+type CpuTimes = [u64; 8];
+
+/// 1、5、15 分钟平均负载
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct LoadAverage {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+}
+
+impl From<LoadAvg> for LoadAverage {
+    fn from(load: LoadAvg) -> Self {
+        Self { one: load.one, five: load.five, fifteen: load.fifteen }
+    }
+}
+
+/// CPU 型号信息，可选字段在无法获取的平台上为 None
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CpuInfo {
+    pub brand: String,
+    pub vendor: String,
+    pub frequency: u64,
+    pub family: Option<String>,
+    pub model: Option<String>,
+    pub l1d_cache: Option<u64>,
+    pub l1i_cache: Option<u64>,
+    pub l2_cache: Option<u64>,
+    pub l3_cache: Option<u64>,
+}
+
+// 启动时读取一次的静态信息
+#[derive(Debug, Clone, Default)]
+struct CpuDetails {
+    family: Option<String>,
+    model: Option<String>,
+    l1d_cache: Option<u64>,
+    l1i_cache: Option<u64>,
+    l2_cache: Option<u64>,
+    l3_cache: Option<u64>,
 }
 
 pub struct CpuMonitor {
     previous_measurement: Option<CpuStats>,
+    details: CpuDetails,
+    history: VecDeque<Vec<f32>>,  // 最近的各核心使用率，用于计算平均值
+    max_frequency: Vec<u64>,      // 各核心的最高频率（MHz）
+    smoothing: f32,               // 总使用率的 EMA 系数，1.0 表示不平滑
+    smoothed_usage: Option<f32>,
+    #[cfg(target_os = "linux")]
+    previous_times: Option<CpuTimes>,  // 上次读取的 /proc/stat 累计节拍
+}
+
+impl CpuStats {
+    // 部分平台的 sysinfo 对某些核心返回 0，只对有读数的核心取平均，全部缺失时为 0
+    pub fn average_frequency(&self) -> u64 {
+        let known: Vec<u64> = self.frequency.iter().copied().filter(|freq| *freq > 0).collect();
+        match known.len() {
+            0 => 0,
+            len => known.iter().sum::<u64>() / len as u64,
+        }
+    }
+}
+
+impl Default for CpuMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl CpuMonitor {
     pub fn new() -> Self {
         Self {
             previous_measurement: None,
+            details: Self::read_details(),
+            history: VecDeque::with_capacity(super::AVERAGE_WINDOW),
+            max_frequency: Self::read_max_frequency(),
+            smoothing: 1.0,
+            smoothed_usage: None,
+            #[cfg(target_os = "linux")]
+            previous_times: Self::read_times(),
+        }
+    }
+
+    pub fn with_smoothing(mut self, alpha: f32) -> Self {
+        self.smoothing = alpha.clamp(f32::EPSILON, 1.0);
+        self
+    }
+
+    pub fn cpu_info(&self, sys: &System) -> CpuInfo {
+        let info = sys.global_cpu_info();
+        // 部分平台的全局频率为 0，退回到第一个核心
+        let frequency = match info.frequency() {
+            0 => sys.cpus().first().map(|cpu| cpu.frequency()).unwrap_or(0),
+            freq => freq,
+        };
+        let vendor = match info.vendor_id() {
+            "" => sys.cpus().first().map(|cpu| cpu.vendor_id()).unwrap_or(""),
+            vendor => vendor,
+        };
+
+        CpuInfo {
+            brand: info.brand().to_string(),
+            vendor: vendor.to_string(),
+            frequency,
+            family: self.details.family.clone(),
+            model: self.details.model.clone(),
+            l1d_cache: self.details.l1d_cache,
+            l1i_cache: self.details.l1i_cache,
+            l2_cache: self.details.l2_cache,
+            l3_cache: self.details.l3_cache,
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn read_details() -> CpuDetails {
+        let mut details = CpuDetails::default();
+
+        // 只需要第一个处理器的信息
+        if let Ok(cpuinfo) = std::fs::read_to_string("/proc/cpuinfo") {
+            for line in cpuinfo.lines().take_while(|line| !line.trim().is_empty()) {
+                if let Some((key, value)) = line.split_once(':') {
+                    match key.trim() {
+                        "cpu family" => details.family = Some(value.trim().to_string()),
+                        "model" => details.model = Some(value.trim().to_string()),
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        let cache_dir = std::path::Path::new("/sys/devices/system/cpu/cpu0/cache");
+        if let Ok(entries) = std::fs::read_dir(cache_dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let read = |name: &str| {
+                    std::fs::read_to_string(path.join(name))
+                        .map(|s| s.trim().to_string())
+                        .ok()
+                };
+                let (Some(level), Some(kind), Some(size)) = (read("level"), read("type"), read("size")) else {
+                    continue;
+                };
+                let size = Self::parse_cache_size(&size);
+                match (level.as_str(), kind.as_str()) {
+                    ("1", "Data") => details.l1d_cache = size,
+                    ("1", "Instruction") => details.l1i_cache = size,
+                    ("2", _) => details.l2_cache = size,
+                    ("3", _) => details.l3_cache = size,
+                    _ => {}
+                }
+            }
         }
+
+        details
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn read_details() -> CpuDetails {
+        CpuDetails::default()
+    }
+
+    // cpufreq 中的 cpuinfo_max_freq 以 kHz 为单位；缺少某个核心的文件时记为 0，
+    // 保证下标与核心编号对应
+    #[cfg(target_os = "linux")]
+    fn read_max_frequency() -> Vec<u64> {
+        let cpu_dir = std::path::Path::new("/sys/devices/system/cpu");
+        (0..)
+            .map(|i| cpu_dir.join(format!("cpu{}", i)))
+            .take_while(|path| path.exists())
+            .map(|path| {
+                std::fs::read_to_string(path.join("cpufreq/cpuinfo_max_freq"))
+                    .ok()
+                    .and_then(|s| s.trim().parse::<u64>().ok())
+                    .map_or(0, |khz| khz / 1000)
+            })
+            .collect()
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn read_max_frequency() -> Vec<u64> {
+        Vec::new()
+    }
+
+    // sysfs 中的缓存大小形如 "48K"、"2048K"、"32M"
+    #[cfg(target_os = "linux")]
+    fn parse_cache_size(size: &str) -> Option<u64> {
+        let (number, multiplier) = match size.chars().last()? {
+            'K' => (&size[..size.len() - 1], 1024),
+            'M' => (&size[..size.len() - 1], 1024 * 1024),
+            _ => (size, 1),
+        };
+        number.parse::<u64>().ok().map(|n| n * multiplier)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn read_times() -> Option<CpuTimes> {
+        Self::parse_times(&std::fs::read_to_string("/proc/stat").ok()?)
+    }
+
+    // 解析汇总行 "cpu  user nice system idle iowait irq softirq steal guest guest_nice"，
+    // guest 时间已计入 user，不再单独累加；旧内核缺少的末尾字段按 0 处理
+    #[cfg(target_os = "linux")]
+    fn parse_times(content: &str) -> Option<CpuTimes> {
+        let fields = content.lines().next()?.strip_prefix("cpu ")?;
+        let mut times = CpuTimes::default();
+        for (time, field) in times.iter_mut().zip(fields.split_whitespace()) {
+            *time = field.parse().ok()?;
+        }
+        Some(times)
+    }
+
+    // 首次读取时只记下基线，返回 None
+    #[cfg(target_os = "linux")]
+    fn breakdown(&mut self) -> Option<CpuBreakdown> {
+        let current = Self::read_times()?;
+        let previous = self.previous_times.replace(current)?;
+        Self::percentages(previous, current)
+    }
+
+    // 两次读数的差值按总节拍换算为百分比，计数没有前进时为 None
+    #[cfg(target_os = "linux")]
+    fn percentages(previous: CpuTimes, current: CpuTimes) -> Option<CpuBreakdown> {
+        let delta: Vec<u64> = current.iter().zip(previous).map(|(now, before)| now.saturating_sub(before)).collect();
+        let total = delta.iter().sum::<u64>();
+        if total == 0 {
+            return None;
+        }
+        let percent = |ticks: u64| ticks as f32 / total as f32 * 100.0;
+        Some(CpuBreakdown {
+            user: percent(delta[0]),
+            nice: percent(delta[1]),
+            system: percent(delta[2]),
+            idle: percent(delta[3]),
+            iowait: percent(delta[4]),
+            irq: percent(delta[5] + delta[6]),
+        })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn breakdown(&mut self) -> Option<CpuBreakdown> {
+        None
+    }
+
+    // 按 alpha * 新值 + (1 - alpha) * 旧值 平滑，首个采样直接取原值
+    fn smooth(&mut self, usage: f32) -> f32 {
+        let smoothed = match self.smoothed_usage {
+            Some(previous) => self.smoothing * usage + (1.0 - self.smoothing) * previous,
+            None => usage,
+        };
+        self.smoothed_usage = Some(smoothed);
+        smoothed
+    }
+
+    // 取标签含 CPU 或 Package 的传感器（如 coretemp 的 "Package id 0"、"cpu_thermal"），
+    // 多路 CPU 时取最高值
+    fn temperature(sys: &System) -> Option<f32> {
+        sys.components()
+            .iter()
+            .filter(|component| {
+                let label = component.label().to_lowercase();
+                label.contains("cpu") || label.contains("package")
+            })
+            .map(|component| component.temperature())
+            .filter(|temperature| temperature.is_finite())
+            .reduce(f32::max)
     }
 
     pub fn collect_stats(&mut self, sys: &System) -> Result<CpuStats> {
@@ -27,16 +317,39 @@ impl CpuMonitor {
             core_usage: Vec::with_capacity(core_count),
             core_count,
             frequency: Vec::with_capacity(core_count),
+            max_frequency: Vec::with_capacity(core_count),
+            average_usage: 0.0,
+            core_average: Vec::with_capacity(core_count),
+            temperature: Self::temperature(sys),
+            breakdown: self.breakdown(),
+            timestamp: SystemTime::now(),
         };
 
         // 收集每个核心的使用率和频率
-        for cpu in sys.cpus() {
+        for (i, cpu) in sys.cpus().iter().enumerate() {
             stats.core_usage.push(cpu.cpu_usage());
             stats.frequency.push(cpu.frequency());
+            stats.max_frequency.push(self.max_frequency.get(i).copied().unwrap_or(0));
         }
 
         // 计算总体CPU使用率
-        stats.total_usage = stats.core_usage.iter().sum::<f32>() / core_count as f32;
+        let total_usage = stats.core_usage.iter().sum::<f32>() / core_count as f32;
+        stats.total_usage = self.smooth(total_usage);
+
+        // 核心数变化（如热插拔）时重新开始统计
+        if self.history.front().is_some_and(|cores| cores.len() != core_count) {
+            self.history.clear();
+        }
+        if self.history.len() == super::AVERAGE_WINDOW {
+            self.history.pop_front();
+        }
+        self.history.push_back(stats.core_usage.clone());
+
+        let samples = self.history.len() as f32;
+        stats.core_average = (0..core_count)
+            .map(|i| self.history.iter().map(|cores| cores[i]).sum::<f32>() / samples)
+            .collect();
+        stats.average_usage = stats.core_average.iter().sum::<f32>() / core_count as f32;
 
         self.previous_measurement = Some(stats.clone());
         Ok(stats)
@@ -51,6 +364,126 @@ impl Clone for CpuStats {
             core_usage: self.core_usage.clone(),
             core_count: self.core_count,
             frequency: self.frequency.clone(),
+            max_frequency: self.max_frequency.clone(),
+            average_usage: self.average_usage,
+            core_average: self.core_average.clone(),
+            temperature: self.temperature,
+            breakdown: self.breakdown,
+            timestamp: self.timestamp,
         }
     }
-} 
\ No newline at end of file
+} 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoothing_converges_to_constant_input() {
+        let mut monitor = CpuMonitor::new().with_smoothing(0.3);
+        assert_eq!(monitor.smooth(0.0), 0.0);
+        let mut previous = 0.0;
+        for _ in 0..50 {
+            let smoothed = monitor.smooth(100.0);
+            assert!(smoothed >= previous && smoothed <= 100.0);
+            previous = smoothed;
+        }
+        assert!(100.0 - previous < 0.01, "{}", previous);
+    }
+
+    #[test]
+    fn smoothing_steps() {
+        let mut monitor = CpuMonitor::new().with_smoothing(0.5);
+        assert_eq!(monitor.smooth(40.0), 40.0);
+        assert_eq!(monitor.smooth(80.0), 60.0);
+        assert_eq!(monitor.smooth(80.0), 70.0);
+    }
+
+    #[test]
+    fn default_alpha_does_not_smooth() {
+        let mut monitor = CpuMonitor::new();
+        for usage in [10.0, 90.0, 0.0, 55.5] {
+            assert_eq!(monitor.smooth(usage), usage);
+        }
+    }
+
+    #[test]
+    fn load_average_from_sysinfo() {
+        let load = LoadAverage::from(LoadAvg { one: 0.5, five: 1.25, fifteen: 2.0 });
+        assert_eq!((load.one, load.five, load.fifteen), (0.5, 1.25, 2.0));
+    }
+
+    #[test]
+    fn load_average_json_round_trip() {
+        let load = LoadAverage { one: 3.5, five: 2.25, fifteen: 0.0 };
+        let json = serde_json::to_string(&load).unwrap();
+        assert_eq!(json, r#"{"one":3.5,"five":2.25,"fifteen":0.0}"#);
+        let parsed: LoadAverage = serde_json::from_str(&json).unwrap();
+        assert_eq!((parsed.one, parsed.five, parsed.fifteen), (3.5, 2.25, 0.0));
+    }
+
+    // 4 核机器上 /proc/stat 的开头部分
+    #[cfg(target_os = "linux")]
+    const PROC_STAT: &str = "\
+cpu  74608 2520 24433 1117073 6176 4054 1521 308 0 0
+cpu0 18642 627 6099 279336 1539 1015 380 77 0 0
+cpu1 18703 631 6120 279222 1546 1013 381 77 0 0
+intr 3374211 21 9 0 0 0 0 0 0 1 0
+ctxt 5898357
+btime 1700000000
+";
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn parse_summary_line() {
+        assert_eq!(
+            CpuMonitor::parse_times(PROC_STAT),
+            Some([74608, 2520, 24433, 1117073, 6176, 4054, 1521, 308]),
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn parse_old_kernel_without_steal() {
+        assert_eq!(
+            CpuMonitor::parse_times("cpu  100 0 50 900\ncpu0 100 0 50 900\n"),
+            Some([100, 0, 50, 900, 0, 0, 0, 0]),
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn parse_rejects_other_content() {
+        assert_eq!(CpuMonitor::parse_times(""), None);
+        assert_eq!(CpuMonitor::parse_times("cpu0 1 2 3 4\n"), None);
+        assert_eq!(CpuMonitor::parse_times("cpu  1 x 3 4\n"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn percentages_from_two_readings() {
+        let previous = [1000, 100, 500, 8000, 200, 50, 50, 0];
+        // 共 1000 个节拍：user 400、nice 50、system 150、idle 300、iowait 50、irq 20 + softirq 30
+        let current = [1400, 150, 650, 8300, 250, 70, 80, 0];
+        let breakdown = CpuMonitor::percentages(previous, current).unwrap();
+        let actual = [breakdown.user, breakdown.nice, breakdown.system, breakdown.idle, breakdown.iowait, breakdown.irq];
+        for (actual, expected) in actual.into_iter().zip([40.0, 5.0, 15.0, 30.0, 5.0, 5.0]) {
+            assert!((actual - expected).abs() < 1e-4, "{} != {}", actual, expected);
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn steal_counts_toward_total() {
+        let previous = [0; 8];
+        let current = [50, 0, 0, 25, 0, 0, 0, 25];
+        let breakdown = CpuMonitor::percentages(previous, current).unwrap();
+        assert_eq!((breakdown.user, breakdown.idle), (50.0, 25.0));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn no_progress_gives_none() {
+        let times = [1, 2, 3, 4, 5, 6, 7, 8];
+        assert!(CpuMonitor::percentages(times, times).is_none());
+    }
+}
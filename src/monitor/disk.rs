@@ -1,38 +1,427 @@
 use sysinfo::{System, SystemExt, DiskExt};
+use serde::{Deserialize, Serialize};
+use crate::cli::DiskSort;
 use crate::error::Result;
+use crate::i18n::{Text, tr};
+use crate::util::glob_match;
+use std::time::{Instant, SystemTime};
+use std::collections::HashMap;
+use std::fmt;
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+#[cfg(unix)]
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, TryRecvError};
+#[cfg(unix)]
+use std::time::Duration;
 
-#[derive(Debug, Clone)]
+// 单个挂载点的探测超时，超过则视为无响应（例如失效的 NFS 挂载）
+#[cfg(unix)]
+const PROBE_TIMEOUT: Duration = Duration::from_millis(500);
+
+// 默认隐藏的伪文件系统：容器层、只读镜像和内存盘
+const PSEUDO_FILE_SYSTEMS: &[&str] = &["overlay", "squashfs", "tmpfs"];
+// 默认隐藏这些目录下的挂载点（snap 包各自挂载一个 squashfs）
+const PSEUDO_MOUNT_PREFIXES: &[&str] = &["/snap"];
+// 即使文件系统类型在上表中也始终显示（容器内根目录常为 overlay）
+const ALWAYS_SHOWN: &[&str] = &["/", "/home"];
+
+#[cfg(unix)]
+type ProbeResult = std::io::Result<Space>;
+
+// statvfs 的结果：总容量、可用容量（字节）和 inode 使用情况
+struct Space {
+    total: u64,
+    available: u64,
+    inodes: Option<InodeStats>,
+}
+
+/// inode 使用情况，tmpfs 等不限 inode 的文件系统报告 0 个，此时不提供
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct InodeStats {
+    pub total: u64,
+    pub used: u64,
+    pub free: u64,
+}
+
+/// 磁盘介质类型；Linux 上按 sysfs 细分出 NVMe，其他平台沿用 sysinfo 的 SSD/HDD 判断
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiskKind {
+    #[serde(rename = "NVMe")]
+    Nvme,
+    #[serde(rename = "SSD")]
+    Ssd,
+    #[serde(rename = "HDD")]
+    Hdd,
+    #[serde(other)]
+    Unknown,
+}
+
+impl DiskKind {
+    /// 按设备名（如 nvme0n1p2、sda1）和是否为机械盘分类，rotational 不可读时使用 fallback
+    pub fn classify(device: &str, rotational: Option<bool>, fallback: DiskKind) -> Self {
+        match rotational {
+            Some(true) => DiskKind::Hdd,
+            Some(false) if device.starts_with("nvme") => DiskKind::Nvme,
+            Some(false) => DiskKind::Ssd,
+            None if device.starts_with("nvme") => DiskKind::Nvme,
+            None => fallback,
+        }
+    }
+}
+
+impl From<sysinfo::DiskKind> for DiskKind {
+    fn from(kind: sysinfo::DiskKind) -> Self {
+        match kind {
+            sysinfo::DiskKind::SSD => DiskKind::Ssd,
+            sysinfo::DiskKind::HDD => DiskKind::Hdd,
+            sysinfo::DiskKind::Unknown(_) => DiskKind::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for DiskKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DiskKind::Nvme => write!(f, "NVMe"),
+            DiskKind::Ssd => write!(f, "SSD"),
+            DiskKind::Hdd => write!(f, "HDD"),
+            DiskKind::Unknown => write!(f, "{}", tr(Text::Unknown)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiskStats {
     pub name: String,
-    pub disk_type: String,
+    pub mount_point: String,
+    pub disk_type: DiskKind,
+    #[serde(default)]
+    pub file_system: String,  // 文件系统类型，如 ext4、xfs
     pub total_space: u64,
     pub used_space: u64,
+    pub available_space: u64,
     pub is_removable: bool,
+    pub mount_options: Vec<String>,  // 挂载选项（如 ro、noatime），无法获取时为空
+    pub error: Option<String>,  // 读取失败的原因，此时容量字段为 0
+    #[serde(default)]
+    pub inodes: Option<InodeStats>,  // 仅 Unix
+    pub read_bytes_per_sec: u64,
+    pub write_bytes_per_sec: u64,
+    #[serde(serialize_with = "super::serialize_timestamp", deserialize_with = "super::deserialize_timestamp")]
+    pub timestamp: SystemTime,  // 采样时刻
 }
 
-pub struct DiskMonitor;
+impl DiskStats {
+    pub fn is_read_only(&self) -> bool {
+        self.mount_options.iter().any(|option| option == "ro")
+    }
+}
+
+pub struct DiskMonitor {
+    dedup: bool,  // 合并同一设备的多个挂载点
+    sort: DiskSort,
+    all_disks: bool,  // 不隐藏伪文件系统
+    exclude: Vec<String>,  // 用户指定的挂载点或文件系统类型通配符
+    previous_io: HashMap<String, (u64, u64)>,  // 设备名 -> 上次的累计 (读取, 写入) 字节
+    last_update: Instant,
+    #[cfg(unix)]
+    probers: HashMap<PathBuf, Prober>,  // 每个挂载点一个常驻探测线程，卡住的挂载点不会累积线程
+}
+
+impl Default for DiskMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl DiskMonitor {
     pub fn new() -> Self {
-        Self
+        Self {
+            dedup: true,
+            sort: DiskSort::Name,
+            all_disks: false,
+            exclude: Vec::new(),
+            previous_io: HashMap::new(),
+            last_update: Instant::now(),
+            #[cfg(unix)]
+            probers: HashMap::new(),
+        }
+    }
+
+    pub fn with_dedup(mut self, dedup: bool) -> Self {
+        self.dedup = dedup;
+        self
+    }
+
+    pub fn with_sort(mut self, sort: DiskSort) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    pub fn with_filter(mut self, all_disks: bool, exclude: Vec<String>) -> Self {
+        self.all_disks = all_disks;
+        self.exclude = exclude;
+        self
     }
 
-    pub fn collect_stats(&self, sys: &System) -> Result<Vec<DiskStats>> {
+    // / 和 /home 始终显示；用户的排除规则对其余挂载点总是生效，内置规则在 --all-disks 时关闭
+    fn is_shown(&self, mount_point: &str, file_system: &str) -> bool {
+        if ALWAYS_SHOWN.contains(&mount_point) {
+            return true;
+        }
+        let excluded = self.exclude.iter()
+            .any(|pattern| glob_match(pattern, mount_point) || glob_match(pattern, file_system));
+        if excluded {
+            return false;
+        }
+        if self.all_disks {
+            return true;
+        }
+        let pseudo_mount = PSEUDO_MOUNT_PREFIXES.iter().any(|prefix| {
+            mount_point.strip_prefix(prefix).is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        });
+        !PSEUDO_FILE_SYSTEMS.contains(&file_system) && !pseudo_mount
+    }
+
+    // 每个挂载点单独采集，单个挂载点失败只标记该项，不影响其余磁盘
+    pub fn collect_stats(&mut self, sys: &System) -> Result<Vec<DiskStats>> {
         let mut stats = Vec::new();
+        let timestamp = SystemTime::now();
+        let mut mount_options = Self::mount_options();
+        let io_rates = self.io_rates();
         
         for disk in sys.disks() {
+            let mount_point = disk.mount_point().to_string_lossy().into_owned();
+            let file_system = String::from_utf8_lossy(disk.file_system()).into_owned();
+            // 在探测前过滤，隐藏的挂载点不会触发 statvfs
+            if !self.is_shown(&mount_point, &file_system) {
+                continue;
+            }
+            let (total_space, available_space, inodes, error) = match self.space(disk) {
+                Ok(space) => (space.total, space.available.min(space.total), space.inodes, None),
+                Err(e) => (0, 0, None, Some(e)),
+            };
+            let name = disk.name().to_string_lossy().into_owned();
+            let device = Self::device_key(&name);
+            let (read_bytes_per_sec, write_bytes_per_sec) =
+                io_rates.get(&device).copied().unwrap_or_default();
             stats.push(DiskStats {
-                name: disk.name().to_string_lossy().into_owned(),
-                disk_type: format!("{:?}", disk.kind()),
-                total_space: disk.total_space(),
-                used_space: disk.total_space() - disk.available_space(),
+                name,
+                mount_options: mount_options.remove(&mount_point).unwrap_or_default(),
+                mount_point,
+                disk_type: DiskKind::classify(&device, Self::rotational(&device), disk.kind().into()),
+                file_system,
+                total_space,
+                used_space: total_space - available_space,
+                available_space,
                 is_removable: disk.is_removable(),
+                error,
+                inodes,
+                read_bytes_per_sec,
+                write_bytes_per_sec,
+                timestamp,
             });
         }
 
+        // 已卸载的挂载点关闭其探测线程（卡住的线程在 statvfs 返回后退出）
+        #[cfg(unix)]
+        self.probers.retain(|path, _| sys.disks().iter().any(|disk| disk.mount_point() == path));
+
+        if self.dedup {
+            stats = Self::dedup_by_device(stats);
+        }
+        Self::sort_disks(&mut stats, self.sort);
+
         Ok(stats)
     }
 
+    // 按设备计算读写速率（字节/秒），首次采样没有基准时为 0。
+    // 先对所有设备算好再查表，同一设备的多个挂载点共用一份结果
+    fn io_rates(&mut self) -> HashMap<String, (u64, u64)> {
+        let now = Instant::now();
+        let interval = now.duration_since(self.last_update).as_secs_f64();
+        let counters = Self::io_counters();
+
+        let rates = counters
+            .iter()
+            .map(|(device, &(read, written))| {
+                let rate = match self.previous_io.get(device) {
+                    Some(&(prev_read, prev_written)) if interval > 0.0 => (
+                        (read.saturating_sub(prev_read) as f64 / interval) as u64,
+                        (written.saturating_sub(prev_written) as f64 / interval) as u64,
+                    ),
+                    _ => (0, 0),
+                };
+                (device.clone(), rate)
+            })
+            .collect();
+
+        self.previous_io = counters;
+        self.last_update = now;
+        rates
+    }
+
+    // sysinfo 不提供磁盘 I/O 计数，读取 /proc/diskstats；扇区数固定以 512 字节计
+    #[cfg(target_os = "linux")]
+    fn io_counters() -> HashMap<String, (u64, u64)> {
+        let Ok(content) = std::fs::read_to_string("/proc/diskstats") else {
+            return HashMap::new();
+        };
+
+        content
+            .lines()
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                let sectors_read: u64 = fields.get(5)?.parse().ok()?;
+                let sectors_written: u64 = fields.get(9)?.parse().ok()?;
+                Some((fields[2].to_string(), (sectors_read * 512, sectors_written * 512)))
+            })
+            .collect()
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn io_counters() -> HashMap<String, (u64, u64)> {
+        HashMap::new()
+    }
+
+    // 分区没有 queue 目录，取所属整盘的 rotational 标志；dm 设备反映底层设备
+    #[cfg(target_os = "linux")]
+    fn rotational(device: &str) -> Option<bool> {
+        let path = std::fs::canonicalize(format!("/sys/class/block/{}", device)).ok()?;
+        let disk = if path.join("partition").exists() { path.parent()?.to_path_buf() } else { path };
+        let value = std::fs::read_to_string(disk.join("queue/rotational")).ok()?;
+        Some(value.trim() == "1")
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn rotational(_device: &str) -> Option<bool> {
+        None
+    }
+
+    // /dev/mapper/* 等符号链接先解析到真实设备（如 dm-0），再去掉 /dev/ 前缀与 diskstats 对应
+    fn device_key(name: &str) -> String {
+        std::fs::canonicalize(name)
+            .ok()
+            .and_then(|path| path.file_name().map(|file| file.to_string_lossy().into_owned()))
+            .unwrap_or_else(|| name.trim_start_matches("/dev/").to_string())
+    }
+
+    // 由挂载点的探测线程 statvfs，超时则跳过；上一次探测仍未返回时不再发起新的探测，直接报告无响应
+    #[cfg(unix)]
+    fn space(&mut self, disk: &sysinfo::Disk) -> std::result::Result<Space, String> {
+        let mount_point = disk.mount_point().to_path_buf();
+        let prober = self.probers
+            .entry(mount_point.clone())
+            .or_insert_with(|| Prober::spawn(mount_point.clone()));
+
+        match prober.probe() {
+            Ok(result) => result.map_err(|e| e.to_string()),
+            Err(ProbeError::Pending) => Err(tr(Text::DiskNotResponding).to_string()),
+            Err(ProbeError::Exited) => {
+                // 下次采样时重新创建探测线程
+                self.probers.remove(&mount_point);
+                Err(tr(Text::DiskProbeExited).to_string())
+            }
+        }
+    }
+
+    // 非 Unix 平台由 sysinfo 刷新磁盘容量
+    #[cfg(not(unix))]
+    fn space(&mut self, disk: &sysinfo::Disk) -> std::result::Result<Space, String> {
+        Ok(Space { total: disk.total_space(), available: disk.available_space(), inodes: None })
+    }
+
+    // 按挂载点读取 /proc/mounts 中的挂载选项，同一挂载点被覆盖挂载时以最后一条为准
+    #[cfg(target_os = "linux")]
+    fn mount_options() -> HashMap<String, Vec<String>> {
+        let Ok(content) = std::fs::read_to_string("/proc/mounts") else {
+            return HashMap::new();
+        };
+
+        content
+            .lines()
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                match fields.as_slice() {
+                    [_, mount_point, _, options, ..] => Some((
+                        Self::unescape_mount_point(mount_point),
+                        options.split(',').map(str::to_string).collect(),
+                    )),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn mount_options() -> HashMap<String, Vec<String>> {
+        HashMap::new()
+    }
+
+    // /proc/mounts 将空格、制表符等转义为 \040 形式的八进制序列
+    #[cfg(target_os = "linux")]
+    fn unescape_mount_point(raw: &str) -> String {
+        let bytes = raw.as_bytes();
+        let mut decoded = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            let escaped = bytes.get(i + 1..i + 4)
+                .filter(|_| bytes[i] == b'\\')
+                .and_then(|digits| u8::from_str_radix(std::str::from_utf8(digits).ok()?, 8).ok());
+            match escaped {
+                Some(byte) => {
+                    decoded.push(byte);
+                    i += 4;
+                }
+                None => {
+                    decoded.push(bytes[i]);
+                    i += 1;
+                }
+            }
+        }
+        String::from_utf8_lossy(&decoded).into_owned()
+    }
+
+    // 绑定挂载和子卷会让同一设备出现多次，按设备名合并并保留最短的挂载点
+    fn dedup_by_device(stats: Vec<DiskStats>) -> Vec<DiskStats> {
+        let mut deduped: Vec<DiskStats> = Vec::with_capacity(stats.len());
+
+        for disk in stats {
+            let existing = if disk.name.is_empty() {
+                None
+            } else {
+                deduped.iter_mut().find(|d| d.name == disk.name)
+            };
+
+            match existing {
+                Some(existing) => {
+                    if disk.mount_point.len() < existing.mount_point.len() {
+                        *existing = disk;
+                    }
+                }
+                None => deduped.push(disk),
+            }
+        }
+
+        deduped
+    }
+
+    // sysinfo 返回的顺序不固定，排序后每次刷新的顺序一致；键相同时按挂载点排列
+    fn sort_disks(stats: &mut [DiskStats], sort: DiskSort) {
+        match sort {
+            DiskSort::Name => stats.sort_by(|a, b| {
+                a.name.cmp(&b.name).then_with(|| a.mount_point.cmp(&b.mount_point))
+            }),
+            DiskSort::Usage => stats.sort_by(|a, b| {
+                let usage = |d: &DiskStats| Self::usage_percentage(d.total_space, d.used_space);
+                usage(b).total_cmp(&usage(a)).then_with(|| a.mount_point.cmp(&b.mount_point))
+            }),
+            DiskSort::Size => stats.sort_by(|a, b| {
+                b.total_space.cmp(&a.total_space).then_with(|| a.mount_point.cmp(&b.mount_point))
+            }),
+        }
+    }
+
     // 计算使用率百分比
     pub fn usage_percentage(total: u64, used: u64) -> f64 {
         if total == 0 {
@@ -41,4 +430,234 @@ impl DiskMonitor {
             (used as f64 / total as f64) * 100.0
         }
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(unix)]
+enum ProbeError {
+    Pending,  // 上一次探测仍未返回
+    Exited,   // 探测线程已退出
+}
+
+// 单个挂载点的常驻探测线程：每收到一次请求执行一次 statvfs
+#[cfg(unix)]
+struct Prober {
+    requests: Sender<()>,
+    results: Receiver<ProbeResult>,
+    outstanding: bool,  // 已发出请求但结果尚未取回
+}
+
+#[cfg(unix)]
+impl Prober {
+    // 发送端随 Prober 一起释放后，线程在当前探测结束时退出
+    fn spawn(path: PathBuf) -> Self {
+        let (requests, pending) = mpsc::channel::<()>();
+        let (sender, results) = mpsc::channel();
+        std::thread::spawn(move || {
+            for () in pending {
+                if sender.send(statvfs_space(&path)).is_err() {
+                    break;
+                }
+            }
+        });
+        Self { requests, results, outstanding: false }
+    }
+
+    // 上一次探测超时后先取回其结果，取回前不再发起新的请求
+    fn probe(&mut self) -> std::result::Result<ProbeResult, ProbeError> {
+        if self.outstanding {
+            return match self.results.try_recv() {
+                Ok(result) => {
+                    self.outstanding = false;
+                    Ok(result)
+                }
+                Err(TryRecvError::Empty) => Err(ProbeError::Pending),
+                Err(TryRecvError::Disconnected) => Err(ProbeError::Exited),
+            };
+        }
+
+        self.requests.send(()).map_err(|_| ProbeError::Exited)?;
+        match self.results.recv_timeout(PROBE_TIMEOUT) {
+            Ok(result) => Ok(result),
+            Err(RecvTimeoutError::Timeout) => {
+                self.outstanding = true;
+                Err(ProbeError::Pending)
+            }
+            Err(RecvTimeoutError::Disconnected) => Err(ProbeError::Exited),
+        }
+    }
+}
+
+// 可用容量按非特权用户计算，与 df 一致；inode 已用数按 f_files - f_ffree 计算，与 df -i 一致
+#[cfg(unix)]
+#[allow(clippy::unnecessary_cast)]  // statvfs 字段宽度随平台变化
+fn statvfs_space(path: &Path) -> ProbeResult {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = CString::new(path.as_os_str().as_bytes())?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+
+    let fragment = stat.f_frsize as u64;
+    let inodes = (stat.f_files > 0).then(|| {
+        let (total, free) = (stat.f_files as u64, stat.f_ffree as u64);
+        InodeStats { total, used: total.saturating_sub(free), free }
+    });
+    Ok(Space {
+        total: stat.f_blocks as u64 * fragment,
+        available: stat.f_bavail as u64 * fragment,
+        inodes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn disk(name: &str, mount_point: &str, total_space: u64, used_space: u64) -> DiskStats {
+        DiskStats {
+            name: name.to_string(),
+            mount_point: mount_point.to_string(),
+            disk_type: DiskKind::Ssd,
+            file_system: "ext4".to_string(),
+            total_space,
+            used_space,
+            available_space: total_space - used_space,
+            is_removable: false,
+            mount_options: Vec::new(),
+            error: None,
+            inodes: None,
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 0,
+            timestamp: SystemTime::UNIX_EPOCH,
+        }
+    }
+
+    // 使用率分别为 50%、90%、10%、90%，/ 与 /backup 使用率相同
+    fn unsorted() -> Vec<DiskStats> {
+        vec![
+            disk("/dev/sdb1", "/data", 1_000, 500),
+            disk("/dev/sda1", "/", 100, 90),
+            disk("/dev/sda2", "/home", 400, 40),
+            disk("/dev/sdc1", "/backup", 2_000, 1_800),
+        ]
+    }
+
+    fn sorted_mounts(sort: DiskSort) -> Vec<String> {
+        let mut stats = unsorted();
+        DiskMonitor::sort_disks(&mut stats, sort);
+        stats.into_iter().map(|disk| disk.mount_point).collect()
+    }
+
+    // (挂载点, 文件系统)
+    const MOUNTS: &[(&str, &str)] = &[
+        ("/", "overlay"),
+        ("/home", "ext4"),
+        ("/data", "xfs"),
+        ("/boot/efi", "vfat"),
+        ("/run", "tmpfs"),
+        ("/dev/shm", "tmpfs"),
+        ("/snap/core/123", "squashfs"),
+        ("/snap", "ext4"),
+        ("/snapshots", "btrfs"),
+        ("/var/lib/docker/overlay2/abc/merged", "overlay"),
+    ];
+
+    fn shown(monitor: &DiskMonitor) -> Vec<&'static str> {
+        MOUNTS.iter()
+            .filter(|(mount, file_system)| monitor.is_shown(mount, file_system))
+            .map(|(mount, _)| *mount)
+            .collect()
+    }
+
+    #[test]
+    fn pseudo_filesystems_hidden_by_default() {
+        assert_eq!(shown(&DiskMonitor::new()), ["/", "/home", "/data", "/boot/efi", "/snapshots"]);
+    }
+
+    #[test]
+    fn all_disks_shows_everything() {
+        assert_eq!(shown(&DiskMonitor::new().with_filter(true, Vec::new())).len(), MOUNTS.len());
+    }
+
+    #[test]
+    fn user_exclude_by_mount_or_file_system() {
+        let monitor = DiskMonitor::new().with_filter(false, vec!["/boot*".to_string(), "xfs".to_string()]);
+        assert_eq!(shown(&monitor), ["/", "/home", "/snapshots"]);
+        let monitor = DiskMonitor::new().with_filter(true, vec!["tmpfs".to_string()]);
+        assert!(!shown(&monitor).contains(&"/run"));
+    }
+
+    #[test]
+    fn root_and_home_always_shown() {
+        for exclude in ["*", "ext4", "overlay", "/*"] {
+            let monitor = DiskMonitor::new().with_filter(false, vec![exclude.to_string()]);
+            let shown = shown(&monitor);
+            assert!(shown.starts_with(&["/", "/home"]), "{}: {:?}", exclude, shown);
+        }
+    }
+
+    #[test]
+    fn sort_by_name() {
+        assert_eq!(sorted_mounts(DiskSort::Name), ["/", "/home", "/data", "/backup"]);
+    }
+
+    #[test]
+    fn sort_by_usage_descending_then_mount() {
+        assert_eq!(sorted_mounts(DiskSort::Usage), ["/", "/backup", "/data", "/home"]);
+    }
+
+    #[test]
+    fn sort_by_size_descending() {
+        assert_eq!(sorted_mounts(DiskSort::Size), ["/backup", "/data", "/home", "/"]);
+    }
+
+    #[test]
+    fn sort_is_stable_across_input_orders() {
+        for sort in [DiskSort::Name, DiskSort::Usage, DiskSort::Size] {
+            let mut reversed = unsorted();
+            reversed.reverse();
+            DiskMonitor::sort_disks(&mut reversed, sort);
+            let mounts: Vec<String> = reversed.into_iter().map(|disk| disk.mount_point).collect();
+            assert_eq!(mounts, sorted_mounts(sort));
+        }
+    }
+
+    #[test]
+    fn classify_by_device_name_and_rotational() {
+        let cases = [
+            ("nvme0n1p2", Some(false), DiskKind::Nvme),
+            ("nvme0n1p2", None, DiskKind::Nvme),
+            ("sda1", Some(false), DiskKind::Ssd),
+            ("sda1", Some(true), DiskKind::Hdd),
+            ("mmcblk0p1", None, DiskKind::Unknown),
+        ];
+        for (device, rotational, kind) in cases {
+            assert_eq!(DiskKind::classify(device, rotational, DiskKind::Unknown), kind, "{device}");
+        }
+        // rotational 不可读时沿用 sysinfo 的结果
+        assert_eq!(DiskKind::classify("sdb", None, DiskKind::Hdd), DiskKind::Hdd);
+        // 机械盘标志优先于设备名
+        assert_eq!(DiskKind::classify("nvme1n1", Some(true), DiskKind::Ssd), DiskKind::Hdd);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn prober_answers_repeated_requests() {
+        let mut prober = Prober::spawn(std::env::temp_dir());
+        for _ in 0..3 {
+            let space = prober.probe().ok().unwrap().unwrap();
+            assert!(space.total >= space.available);
+        }
+        assert!(!prober.outstanding);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn missing_mount_reports_error() {
+        let mut prober = Prober::spawn(PathBuf::from("/nonexistent/litemon"));
+        assert!(matches!(prober.probe(), Ok(Err(_))));
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use crate::error::Result;
+
+/// 内核熵池状态（仅 Linux）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntropyStats {
+    pub available: u64,
+    pub pool_size: u64,
+}
+
+pub struct EntropyMonitor;
+
+impl Default for EntropyMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EntropyMonitor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn collect_stats(&self) -> Result<EntropyStats> {
+        Ok(EntropyStats {
+            available: Self::read_value("/proc/sys/kernel/random/entropy_avail")?,
+            pool_size: Self::read_value("/proc/sys/kernel/random/poolsize")?,
+        })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn collect_stats(&self) -> Result<EntropyStats> {
+        Err(std::io::Error::from(std::io::ErrorKind::Unsupported).into())
+    }
+
+    #[cfg(target_os = "linux")]
+    fn read_value(path: &str) -> Result<u64> {
+        std::fs::read_to_string(path)?
+            .trim()
+            .parse()
+            .map_err(|_| std::io::Error::from(std::io::ErrorKind::InvalidData).into())
+    }
+}
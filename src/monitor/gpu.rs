@@ -1,32 +1,115 @@
 use nvml_wrapper::Nvml;
+use nvml_wrapper::error::NvmlError;
+use serde::{Deserialize, Serialize};
+use nvml_wrapper::bitmasks::device::ThrottleReasons;
 use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
-use crate::error::Result;
+use crate::error::{Result, LiteMonError};
+use crate::i18n::{Text, tr};
+use std::time::SystemTime;
+#[cfg(target_os = "linux")]
+use std::path::{Path, PathBuf};
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct GpuStats {
     pub name: String,
     pub utilization: u32,
     pub memory_used: u64,
     pub memory_total: u64,
     pub temperature: u32,
+    pub throttle_reasons: Vec<String>,  // 当前降频原因的标识，为空表示未降频
+    /// 风扇转速（百分比），被动散热的数据中心卡等不支持时为 None
+    #[serde(default)]
+    pub fan_speed_percent: Option<u32>,
+    /// 当前功耗（瓦），不支持时为 None
+    #[serde(default)]
+    pub power_usage_watts: Option<u32>,
+    #[serde(serialize_with = "super::serialize_timestamp", deserialize_with = "super::deserialize_timestamp")]
+    pub timestamp: SystemTime,  // 采样时刻
+}
+
+impl GpuStats {
+    /// 当前语言下的降频原因，以逗号分隔
+    pub fn throttle_text(&self) -> String {
+        self.throttle_reasons.iter()
+            .map(|reason| throttle_reason_label(reason))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+// 降频原因标识在当前语言下的文本；未知的标识（如其他版本的远程主机上报的）原样显示
+fn throttle_reason_label(reason: &str) -> &str {
+    let text = match reason {
+        "applications_clocks_setting" => Text::ThrottleAppClocks,
+        "sw_power_cap" => Text::ThrottleSwPowerCap,
+        "hw_slowdown" => Text::ThrottleHwSlowdown,
+        "sync_boost" => Text::ThrottleSyncBoost,
+        "sw_thermal_slowdown" => Text::ThrottleSwThermal,
+        "hw_thermal_slowdown" => Text::ThrottleHwThermal,
+        "hw_power_brake_slowdown" => Text::ThrottleHwPowerBrake,
+        "display_clock_setting" => Text::ThrottleDisplayClock,
+        _ => return reason,
+    };
+    tr(text)
+}
+
+/// GPU 数据来源，每个后端负责一类硬件
+pub trait GpuBackend {
+    /// 第一块 GPU 的读数。界面只显示一块 GPU，其余设备不必查询，出错也不影响第一块
+    fn collect(&self) -> Result<GpuStats>;
 }
 
 pub struct GpuMonitor {
-    nvml: Nvml,
+    backend: Box<dyn GpuBackend>,
 }
 
 impl GpuMonitor {
+    // 依次探测 NVML 和 AMD sysfs，使用第一个初始化成功的后端
+    pub fn new() -> Result<Self> {
+        if let Ok(backend) = NvmlBackend::new() {
+            return Ok(Self { backend: Box::new(backend) });
+        }
+        let backend = AmdBackend::new()?;
+        Ok(Self { backend: Box::new(backend) })
+    }
+
+    // 使用指定的后端，用于接入其他硬件或在测试中替换数据来源
+    pub fn with_backend(backend: Box<dyn GpuBackend>) -> Self {
+        Self { backend }
+    }
+
+    pub fn collect_stats(&self) -> Result<GpuStats> {
+        self.backend.collect()
+    }
+}
+
+/// NVIDIA GPU，通过 NVML 查询
+pub struct NvmlBackend {
+    nvml: Nvml,
+}
+
+impl NvmlBackend {
     pub fn new() -> Result<Self> {
         let nvml = Nvml::init()?;
+        if nvml.device_count()? == 0 {
+            return Err(LiteMonError::NoGpuFound);
+        }
         Ok(Self { nvml })
     }
 
-    pub fn collect_stats(&self) -> Result<GpuStats> {
-        let device = self.nvml.device_by_index(0)?;  // 获取第一个 GPU
+    fn device_stats(&self, index: u32) -> Result<GpuStats> {
+        let device = self.nvml.device_by_index(index)?;
         let name = device.name()?;
         let utilization = device.utilization_rates()?.gpu;
         let memory = device.memory_info()?;
         let temperature = device.temperature(TemperatureSensor::Gpu)?;
+        // 部分旧型号不支持查询降频原因，此时视为未降频
+        let throttle_reasons = device.current_throttle_reasons()
+            .map(Self::describe_throttle_reasons)
+            .unwrap_or_default();
+        let fan_speed_percent = Self::optional(device.fan_speed(0))?;
+        // NVML 的功耗单位为毫瓦
+        let power_usage_watts = Self::optional(device.power_usage())?.map(|milliwatts| milliwatts / 1000);
 
         Ok(GpuStats {
             name,
@@ -34,6 +117,137 @@ impl GpuMonitor {
             memory_used: memory.used,
             memory_total: memory.total,
             temperature,
+            throttle_reasons,
+            fan_speed_percent,
+            power_usage_watts,
+            timestamp: SystemTime::now(),
         })
     }
-} 
\ No newline at end of file
+
+    // 型号不支持的查询返回 None，其他错误照常上报
+    fn optional<T>(result: std::result::Result<T, NvmlError>) -> Result<Option<T>> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(NvmlError::NotSupported) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    // 将 NVML 降频标志位转换为原因标识（见 throttle_reason_label）；空闲降频是正常行为，不列出
+    fn describe_throttle_reasons(reasons: ThrottleReasons) -> Vec<String> {
+        let names = [
+            (ThrottleReasons::APPLICATIONS_CLOCKS_SETTING, "applications_clocks_setting"),
+            (ThrottleReasons::SW_POWER_CAP, "sw_power_cap"),
+            (ThrottleReasons::HW_SLOWDOWN, "hw_slowdown"),
+            (ThrottleReasons::SYNC_BOOST, "sync_boost"),
+            (ThrottleReasons::SW_THERMAL_SLOWDOWN, "sw_thermal_slowdown"),
+            (ThrottleReasons::HW_THERMAL_SLOWDOWN, "hw_thermal_slowdown"),
+            (ThrottleReasons::HW_POWER_BRAKE_SLOWDOWN, "hw_power_brake_slowdown"),
+            (ThrottleReasons::DISPLAY_CLOCK_SETTING, "display_clock_setting"),
+        ];
+
+        names.iter()
+            .filter(|(flag, _)| reasons.contains(*flag))
+            .map(|(_, name)| name.to_string())
+            .collect()
+    }
+}
+
+impl GpuBackend for NvmlBackend {
+    fn collect(&self) -> Result<GpuStats> {
+        self.device_stats(0)
+    }
+}
+
+// PCI 厂商号：AMD
+#[cfg(target_os = "linux")]
+const AMD_VENDOR_ID: &str = "0x1002";
+
+/// AMD GPU，读取 amdgpu 驱动在 /sys/class/drm/card*/device 下导出的文件
+pub struct AmdBackend {
+    #[cfg(target_os = "linux")]
+    devices: Vec<PathBuf>,  // 各显卡的 device 目录
+}
+
+impl AmdBackend {
+    #[cfg(target_os = "linux")]
+    pub fn new() -> Result<Self> {
+        let drm = Path::new("/sys/class/drm");
+        let mut devices: Vec<PathBuf> = std::fs::read_dir(drm)?
+            .flatten()
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            // card0-DP-1 之类是显示接口而不是显卡
+            .filter(|name| name.starts_with("card") && !name.contains('-'))
+            .map(|name| drm.join(name).join("device"))
+            .filter(|device| {
+                Self::read(device, "vendor").is_some_and(|vendor| vendor == AMD_VENDOR_ID)
+                    && device.join("gpu_busy_percent").exists()
+            })
+            .collect();
+        if devices.is_empty() {
+            return Err(LiteMonError::NoGpuFound);
+        }
+        devices.sort();
+        Ok(Self { devices })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn new() -> Result<Self> {
+        Err(LiteMonError::NoGpuFound)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn read(dir: &Path, name: &str) -> Option<String> {
+        std::fs::read_to_string(dir.join(name)).ok().map(|s| s.trim().to_string())
+    }
+
+    #[cfg(target_os = "linux")]
+    fn device_stats(device: &Path) -> Result<GpuStats> {
+        let utilization = std::fs::read_to_string(device.join("gpu_busy_percent"))?
+            .trim()
+            .parse()
+            .unwrap_or(0);
+        let bytes = |name: &str| Self::read(device, name).and_then(|s| s.parse::<u64>().ok()).unwrap_or(0);
+
+        // hwmon 中的温度单位为毫摄氏度，风扇 PWM 取值 0-255，功耗单位为微瓦
+        let hwmon = std::fs::read_dir(device.join("hwmon"))
+            .ok()
+            .and_then(|entries| entries.flatten().next())
+            .map(|hwmon| hwmon.path());
+        let hwmon_value = |name: &str| hwmon.as_deref()
+            .and_then(|hwmon| Self::read(hwmon, name))
+            .and_then(|value| value.parse::<u64>().ok());
+        let temperature = hwmon_value("temp1_input").map_or(0, |millis| (millis / 1000) as u32);
+        let fan_speed_percent = hwmon_value("pwm1").map(|pwm| (pwm * 100 / 255) as u32);
+        let power_usage_watts = hwmon_value("power1_average").map(|micros| (micros / 1_000_000) as u32);
+
+        let name = Self::read(device, "product_name")
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| "AMD GPU".to_string());
+
+        Ok(GpuStats {
+            name,
+            utilization,
+            memory_used: bytes("mem_info_vram_used"),
+            memory_total: bytes("mem_info_vram_total"),
+            temperature,
+            throttle_reasons: Vec::new(),  // sysfs 不提供降频原因
+            fan_speed_percent,
+            power_usage_watts,
+            timestamp: SystemTime::now(),
+        })
+    }
+}
+
+impl GpuBackend for AmdBackend {
+    #[cfg(target_os = "linux")]
+    fn collect(&self) -> Result<GpuStats> {
+        let device = self.devices.first().ok_or(LiteMonError::NoGpuFound)?;
+        Self::device_stats(device)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn collect(&self) -> Result<GpuStats> {
+        Err(LiteMonError::NoGpuFound)
+    }
+}
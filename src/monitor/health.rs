@@ -0,0 +1,95 @@
+use super::cpu::CpuStats;
+use super::disk::{DiskMonitor, DiskStats};
+use super::memory::MemoryStats;
+use serde::{Deserialize, Serialize};
+use crate::i18n::{Text, tr};
+
+/// 各资源在健康分中的权重，计算时会按总和归一化
+#[derive(Debug, Clone, Copy)]
+pub struct HealthWeights {
+    pub cpu: f64,
+    pub memory: f64,
+    pub disk: f64,
+    pub swap: f64,
+}
+
+impl Default for HealthWeights {
+    fn default() -> Self {
+        Self {
+            cpu: 0.4,
+            memory: 0.3,
+            disk: 0.2,
+            swap: 0.1,
+        }
+    }
+}
+
+/// 综合健康分（0-100，越高越健康）及各资源的压力百分比
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthScore {
+    pub score: f64,
+    pub cpu: f64,
+    pub memory: f64,
+    pub disk: f64,
+    pub swap: f64,
+    pub disk_mount: Option<String>,  // 使用率最高的分区挂载点
+}
+
+impl HealthScore {
+    // 各资源压力映射：
+    // - CPU：总体使用率
+    // - 内存：已用 / 总量
+    // - 磁盘：使用率最高的分区
+    // - 交换分区：已用 / 总量，未启用时为 0
+    // 健康分 = 100 - 加权平均压力
+    pub fn compute(
+        cpu: &CpuStats,
+        memory: &MemoryStats,
+        disks: &[DiskStats],
+        weights: &HealthWeights,
+    ) -> Self {
+        let cpu_pressure = (cpu.total_usage as f64).clamp(0.0, 100.0);
+        let memory_pressure = DiskMonitor::usage_percentage(memory.total, memory.used);
+        let fullest = disks.iter()
+            .map(|disk| (disk, DiskMonitor::usage_percentage(disk.total_space, disk.used_space)))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+        let disk_pressure = fullest.map_or(0.0, |(_, usage)| usage);
+        let swap_pressure = DiskMonitor::usage_percentage(memory.swap_total, memory.swap_used);
+
+        let total_weight = weights.cpu + weights.memory + weights.disk + weights.swap;
+        let pressure = if total_weight > 0.0 {
+            (cpu_pressure * weights.cpu
+                + memory_pressure * weights.memory
+                + disk_pressure * weights.disk
+                + swap_pressure * weights.swap)
+                / total_weight
+        } else {
+            0.0
+        };
+
+        Self {
+            score: (100.0 - pressure).clamp(0.0, 100.0),
+            cpu: cpu_pressure,
+            memory: memory_pressure,
+            disk: disk_pressure,
+            swap: swap_pressure,
+            disk_mount: fullest.map(|(disk, _)| disk.mount_point.clone()),
+        }
+    }
+
+    // 当前压力最高的资源（名称, 压力百分比），即最值得先排查的瓶颈
+    pub fn bottleneck(&self) -> (String, f64) {
+        let disk = match &self.disk_mount {
+            Some(mount) => format!("{} {}", tr(Text::Disk), mount),
+            None => tr(Text::Disk).to_string(),
+        };
+        [
+            ("CPU".to_string(), self.cpu),
+            (tr(Text::Memory).to_string(), self.memory),
+            (disk, self.disk),
+            (tr(Text::SwapShort).to_string(), self.swap),
+        ]
+        .into_iter()
+        .fold(("CPU".to_string(), f64::MIN), |max, item| if item.1 > max.1 { item } else { max })
+    }
+}
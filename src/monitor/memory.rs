@@ -1,45 +1,293 @@
 use sysinfo::{System, SystemExt};
+use serde::{Deserialize, Serialize};
+use crate::cli::UnitStyle;
 use crate::error::Result;
+use super::disk::DiskMonitor;
+use std::time::SystemTime;
 
+/// 单个交换设备（分区或文件）的使用情况
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwapDevice {
+    pub name: String,
+    pub kind: String,
+    pub size: u64,
+    pub used: u64,
+    pub priority: i32,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
 pub struct MemoryStats {
     pub total: u64,
     pub used: u64,
     pub available: u64,
     pub swap_total: u64,
     pub swap_used: u64,
+    /// 各交换设备明细，无法获取时为空，仅显示总量
+    pub swap_devices: Vec<SwapDevice>,
+    /// 已提交内存（仅 Windows，对应任务管理器中的"已提交"）
+    pub committed: Option<u64>,
+    /// 提交限制（物理内存 + 页面文件）
+    pub commit_limit: Option<u64>,
+    /// 块设备缓冲（仅 Linux，按 cgroup 限制计算时和其他平台为 0）
+    #[serde(default)]
+    pub buffers: u64,
+    /// 页缓存加可回收的 slab（Cached + SReclaimable），同上
+    #[serde(default)]
+    pub cached: u64,
+    /// 程序实际占用的内存（MemTotal - MemFree - 缓冲 - 缓存）；无明细时为 0
+    #[serde(default)]
+    pub truly_used: u64,
+    #[serde(serialize_with = "super::serialize_timestamp", deserialize_with = "super::deserialize_timestamp")]
+    pub timestamp: SystemTime,  // 采样时刻
 }
 
-pub struct MemoryMonitor;
+impl MemoryStats {
+    /// 交换分区使用率，未启用交换分区（总量为 0）时为 None
+    pub fn swap_usage(&self) -> Option<f64> {
+        (self.swap_total > 0).then(|| DiskMonitor::usage_percentage(self.swap_total, self.swap_used))
+    }
+
+    // 只有用量的内存读数，供各模块的测试使用
+    #[cfg(test)]
+    pub(crate) fn with_usage(total: u64, used: u64, swap_total: u64, swap_used: u64) -> Self {
+        Self {
+            total,
+            used,
+            available: total - used,
+            swap_total,
+            swap_used,
+            swap_devices: Vec::new(),
+            committed: None,
+            commit_limit: None,
+            buffers: 0,
+            cached: 0,
+            truly_used: 0,
+            timestamp: SystemTime::UNIX_EPOCH,
+        }
+    }
+}
+
+pub struct MemoryMonitor {
+    host_memory: bool,  // 忽略 cgroup 限制，始终报告宿主机内存
+}
+
+impl Default for MemoryMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl MemoryMonitor {
     pub fn new() -> Self {
-        Self
+        Self { host_memory: false }
     }
 
+    pub fn with_host_memory(mut self, host_memory: bool) -> Self {
+        self.host_memory = host_memory;
+        self
+    }
+
+    // 容器内 sysinfo 报告的是宿主机内存，cgroup 限制小于宿主机总量时改按限制计算
     pub fn collect_stats(&self, sys: &System) -> Result<MemoryStats> {
+        let (committed, commit_limit) = match Self::commit_charge() {
+            Some((committed, limit)) => (Some(committed), Some(limit)),
+            None => (None, None),
+        };
+
+        let (mut total, mut used, mut available) = (sys.total_memory(), sys.used_memory(), sys.available_memory());
+        // 缓冲和缓存是宿主机范围的数据，按 cgroup 限制计算时不显示明细
+        let mut breakdown = Self::breakdown();
+        let cgroup = Self::cgroup_memory().filter(|(limit, _)| !self.host_memory && *limit < total);
+        if let Some((limit, cgroup_used)) = cgroup {
+            total = limit;
+            used = cgroup_used.min(limit);
+            available = (limit - used).min(available);
+            breakdown = None;
+        }
+        let (buffers, cached, truly_used) = breakdown.unwrap_or_default();
+
         Ok(MemoryStats {
-            total: sys.total_memory(),
-            used: sys.used_memory(),
-            available: sys.available_memory(),
+            total,
+            used,
+            available,
             swap_total: sys.total_swap(),
             swap_used: sys.used_swap(),
+            swap_devices: Self::swap_devices(),
+            committed,
+            commit_limit,
+            buffers,
+            cached,
+            truly_used,
+            timestamp: SystemTime::now(),
         })
     }
 
-    pub fn format_bytes(bytes: u64) -> String {
-        const KB: f64 = 1024.0;
-        const MB: f64 = KB * 1024.0;
-        const GB: f64 = MB * 1024.0;
+    // 返回 cgroup 的 (内存限制, 已用)，优先 v2，其次 v1。未设限制时 v2 为 "max" 无法解析，
+    // v1 为接近 i64::MAX 的大数，由调用方与宿主机总量比较后忽略。
+    // 已用量扣除可回收的非活跃文件缓存，与 docker stats 的算法一致
+    #[cfg(target_os = "linux")]
+    fn cgroup_memory() -> Option<(u64, u64)> {
+        let read = |path: &str| std::fs::read_to_string(path).ok();
+        let (limit, current, stat, inactive_key) = match read("/sys/fs/cgroup/memory.max") {
+            Some(limit) => (
+                limit,
+                read("/sys/fs/cgroup/memory.current")?,
+                read("/sys/fs/cgroup/memory.stat"),
+                "inactive_file ",
+            ),
+            None => (
+                read("/sys/fs/cgroup/memory/memory.limit_in_bytes")?,
+                read("/sys/fs/cgroup/memory/memory.usage_in_bytes")?,
+                read("/sys/fs/cgroup/memory/memory.stat"),
+                "total_inactive_file ",
+            ),
+        };
+        let limit: u64 = limit.trim().parse().ok()?;
+        let current: u64 = current.trim().parse().ok()?;
+        let inactive = stat
+            .and_then(|stat| {
+                stat.lines().find_map(|line| line.strip_prefix(inactive_key)?.trim().parse::<u64>().ok())
+            })
+            .unwrap_or(0);
+        Some((limit, current.saturating_sub(inactive)))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn cgroup_memory() -> Option<(u64, u64)> {
+        None
+    }
+
+    // 解析 /proc/meminfo，返回 (缓冲, 缓存, 实际占用)，单位为字节。
+    // sysinfo 的已用内存为 MemTotal - MemAvailable，其中仍含部分缓存，
+    // 实际占用从 MemTotal - MemFree 中扣除 Buffers、Cached 和 SReclaimable
+    #[cfg(target_os = "linux")]
+    fn breakdown() -> Option<(u64, u64, u64)> {
+        let content = std::fs::read_to_string("/proc/meminfo").ok()?;
+        let field = |key: &str| {
+            content.lines().find_map(|line| {
+                let value = line.strip_prefix(key)?.strip_prefix(':')?;
+                value.trim().trim_end_matches("kB").trim().parse::<u64>().ok().map(|kib| kib * 1024)
+            })
+        };
+        let (total, free, buffers) = (field("MemTotal")?, field("MemFree")?, field("Buffers")?);
+        let cached = field("Cached")? + field("SReclaimable").unwrap_or(0);
+        Some((buffers, cached, total.saturating_sub(free + buffers + cached)))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn breakdown() -> Option<(u64, u64, u64)> {
+        None
+    }
 
-        let bytes = bytes as f64;
-        if bytes >= GB {
-            format!("{:.2} GB", bytes / GB)
-        } else if bytes >= MB {
-            format!("{:.2} MB", bytes / MB)
-        } else if bytes >= KB {
-            format!("{:.2} KB", bytes / KB)
+    // 解析 /proc/swaps，大小以 KiB 为单位
+    #[cfg(target_os = "linux")]
+    fn swap_devices() -> Vec<SwapDevice> {
+        let Ok(content) = std::fs::read_to_string("/proc/swaps") else {
+            return Vec::new();
+        };
+
+        content
+            .lines()
+            .skip(1)
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                match fields.as_slice() {
+                    [name, kind, size, used, priority] => Some(SwapDevice {
+                        name: name.to_string(),
+                        kind: kind.to_string(),
+                        size: size.parse::<u64>().ok()? * 1024,
+                        used: used.parse::<u64>().ok()? * 1024,
+                        priority: priority.parse().ok()?,
+                    }),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn swap_devices() -> Vec<SwapDevice> {
+        Vec::new()
+    }
+
+    // 读取提交费用（已提交, 提交限制），sysinfo 的已用内存只反映工作集
+    #[cfg(windows)]
+    fn commit_charge() -> Option<(u64, u64)> {
+        use winapi::um::sysinfoapi::{GlobalMemoryStatusEx, MEMORYSTATUSEX};
+
+        let mut status: MEMORYSTATUSEX = unsafe { std::mem::zeroed() };
+        status.dwLength = std::mem::size_of::<MEMORYSTATUSEX>() as u32;
+        if unsafe { GlobalMemoryStatusEx(&mut status) } == 0 {
+            return None;
+        }
+
+        let limit = status.ullTotalPageFile;
+        Some((limit.saturating_sub(status.ullAvailPageFile), limit))
+    }
+
+    #[cfg(not(windows))]
+    fn commit_charge() -> Option<(u64, u64)> {
+        None
+    }
+
+    pub fn format_bytes(bytes: u64, units: UnitStyle) -> String {
+        Self::format_scaled(bytes as f64, units)
+    }
+
+    // 二进制按 1024 进位并使用 IEC 单位（KiB/MiB/GiB），十进制按 1000 进位（KB/MB/GB）
+    pub fn format_scaled(value: f64, units: UnitStyle) -> String {
+        let (base, [kb, mb, gb]) = match units {
+            UnitStyle::Binary => (1024.0, ["KiB", "MiB", "GiB"]),
+            UnitStyle::Decimal => (1000.0, ["KB", "MB", "GB"]),
+        };
+        let (mb_base, gb_base) = (base * base, base * base * base);
+
+        if value >= gb_base {
+            format!("{:.2} {}", value / gb_base, gb)
+        } else if value >= mb_base {
+            format!("{:.2} {}", value / mb_base, mb)
+        } else if value >= base {
+            format!("{:.2} {}", value / base, kb)
         } else {
-            format!("{:.0} B", bytes)
+            format!("{:.0} B", value)
         }
     }
-} 
\ No newline at end of file
+} 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swap_disabled_has_no_usage() {
+        assert_eq!(MemoryStats::with_usage(100, 50, 0, 0).swap_usage(), None);
+        assert_eq!(MemoryStats::with_usage(100, 50, 0, 10).swap_usage(), None);
+    }
+
+    #[test]
+    fn swap_usage_percent() {
+        assert_eq!(MemoryStats::with_usage(100, 50, 200, 50).swap_usage(), Some(25.0));
+        assert_eq!(MemoryStats::with_usage(100, 50, 200, 0).swap_usage(), Some(0.0));
+    }
+
+    #[test]
+    fn binary_boundaries() {
+        let format = |bytes| MemoryMonitor::format_bytes(bytes, UnitStyle::Binary);
+        assert_eq!(format(0), "0 B");
+        assert_eq!(format(1000), "1000 B");
+        assert_eq!(format(1023), "1023 B");
+        assert_eq!(format(1024), "1.00 KiB");
+        assert_eq!(format(1024 * 1024), "1.00 MiB");
+        assert_eq!(format(1024 * 1024 * 1024), "1.00 GiB");
+        assert_eq!(format(5 * 1024 * 1024 * 1024 * 1024), "5120.00 GiB");
+    }
+
+    #[test]
+    fn decimal_boundaries() {
+        let format = |bytes| MemoryMonitor::format_bytes(bytes, UnitStyle::Decimal);
+        assert_eq!(format(999), "999 B");
+        assert_eq!(format(1000), "1.00 KB");
+        assert_eq!(format(1024), "1.02 KB");
+        assert_eq!(format(1_000_000), "1.00 MB");
+        assert_eq!(format(1_000_000_000), "1.00 GB");
+    }
+}
@@ -1,51 +1,395 @@
+pub mod capabilities;
+pub mod clock;
+pub mod connections;
 pub mod cpu;
 pub mod memory;
 pub mod disk;
+pub mod entropy;
 pub mod network;
 pub mod gpu;
+pub mod health;
+pub mod process;
+pub mod raid;
 
-use sysinfo::{System, SystemExt, CpuExt};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sysinfo::{Pid, PidExt, System, SystemExt, ProcessExt};
+use crate::cli::DiskSort;
 use crate::error::{Result, LiteMonError};
-use self::cpu::{CpuMonitor, CpuStats};
+use crate::i18n::{Text, fill, tr};
+use self::capabilities::Capabilities;
+use self::clock::{Clock, SystemClock};
+use self::cpu::{CpuInfo, CpuMonitor, CpuStats, LoadAverage};
 use self::memory::{MemoryMonitor, MemoryStats};
 use self::disk::{DiskMonitor, DiskStats};
 use self::network::{NetworkMonitor, NetworkStats};
+use self::entropy::{EntropyMonitor, EntropyStats};
+use self::connections::{ConnectionMonitor, ConnectionStats, TcpSummary};
+use self::health::{HealthScore, HealthWeights};
+use self::process::{ProcessMonitor, ProcessSort, ProcessStats};
+use self::raid::{RaidMonitor, RaidStatus};
+use self::gpu::GpuStats;
+
+/// 平均值模式使用的采样窗口大小
+pub const AVERAGE_WINDOW: usize = 10;
+
+// 默认的 GPU 数据缓存时长
+const DEFAULT_GPU_INTERVAL: Duration = Duration::from_secs(1);
+// Unix 下重新列举挂载点的间隔
+#[cfg(unix)]
+const DISK_LIST_INTERVAL: Duration = Duration::from_secs(30);
+
+/// JSON 输出的结构版本，字段有不兼容的变化（改名、删除、改变单位）时加一
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// 子系统连续失败这么多次后视为不可用，TUI 在对应面板显示原因而不是留空
+pub const FAILURE_THRESHOLD: u32 = 3;
+
+/// 会记录连续失败次数的子系统
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Subsystem {
+    Cpu,
+    Memory,
+    Disk,
+    Network,
+    Gpu,
+}
+
+// 一个子系统的连续失败次数和最近一次错误
+#[derive(Debug, Default)]
+struct Failures {
+    consecutive: u32,
+    last_error: Option<String>,
+}
+
+/// 一次完整采样，TUI 和外部输出共享同一份数据，避免重复采集
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Sample {
+    /// 始终为 [`SCHEMA_VERSION`]；旧版本的远程主机不带该字段，反序列化为 0
+    #[serde(default)]
+    pub schema_version: u32,
+    #[serde(serialize_with = "serialize_timestamp", deserialize_with = "deserialize_timestamp")]
+    pub timestamp: SystemTime,
+    pub cpu_info: CpuInfo,
+    #[serde(default)]
+    pub uptime: u64,  // 系统运行时间（秒）
+    #[serde(default)]
+    pub boot_time: u64,  // 启动时刻（Unix 秒）
+    pub cpu: Option<CpuStats>,
+    pub load_average: Option<LoadAverage>,
+    pub memory: Option<MemoryStats>,
+    pub disks: Vec<DiskStats>,
+    pub networks: Vec<NetworkStats>,
+    pub tcp: Option<TcpSummary>,
+    pub gpu: Option<GpuStats>,
+    pub health: Option<HealthScore>,
+    pub entropy: Option<EntropyStats>,
+    pub raid: Option<Vec<RaidStatus>>,
+    /// 连续失败达到 [`FAILURE_THRESHOLD`] 次的子系统及最近一次错误
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub unavailable: BTreeMap<Subsystem, String>,
+    /// 各条 TCP 连接（--connections），未启用时为 None
+    #[serde(default)]
+    pub connections: Option<Vec<ConnectionStats>>,
+    /// 全部进程，按 CPU 降序（--group-processes 时为进程树的根）；数量较多，不写入 JSON 输出
+    #[serde(skip)]
+    pub processes: Vec<ProcessStats>,
+}
+
+/// 每个周期需要向 sysinfo 刷新的数据，由启用的监控项决定。
+/// 进程列表显示 CPU 和内存占用，只要两者之一启用就会刷新
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RefreshSpec {
+    pub cpu: bool,
+    pub memory: bool,
+    pub disk: bool,
+    pub network: bool,
+}
+
+impl RefreshSpec {
+    pub const fn all() -> Self {
+        Self { cpu: true, memory: true, disk: true, network: true }
+    }
+
+    pub fn is_all(&self) -> bool {
+        *self == Self::all()
+    }
+
+    fn processes(&self) -> bool {
+        self.cpu || self.memory
+    }
+}
+
+impl Default for RefreshSpec {
+    fn default() -> Self {
+        Self::all()
+    }
+}
 
 pub struct Monitor {
     sys: System,
+    refresh_spec: RefreshSpec,
     gpu_monitor: Option<gpu::GpuMonitor>,
-    cached_gpu_stats: Option<gpu::GpuStats>,
-    last_gpu_update: std::time::Instant,
+    cached_gpu_stats: Option<GpuStats>,
+    last_gpu_update: Option<Instant>,  // None 表示尚未查询，第一次刷新时立即查询
+    gpu_interval: Duration,  // GPU 数据缓存时长，NVML 查询较慢时可以调大
+    #[cfg(unix)]
+    last_disk_list_update: Instant,
+    clock: Arc<dyn Clock>,   // GPU 缓存和网络速率的计时来源
     cpu_monitor: CpuMonitor,
     memory_monitor: MemoryMonitor,
     disk_monitor: DiskMonitor,
     network_monitor: NetworkMonitor,
+    connection_monitor: ConnectionMonitor,
+    connections: bool,  // 是否采集各条 TCP 连接
+    processes: bool,    // 是否采集进程表，只有显示进程的输出需要
+    process_monitor: ProcessMonitor,
+    entropy_monitor: Option<EntropyMonitor>,
+    raid_monitor: Option<RaidMonitor>,
+    health_weights: HealthWeights,
+    failures: HashMap<Subsystem, Failures>,
+}
+
+impl Default for Monitor {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Monitor {
+    /// sysinfo 计算 CPU 使用率所需的最短采样间隔
+    pub const MINIMUM_WARMUP: Duration = System::MINIMUM_CPU_UPDATE_INTERVAL;
+
     pub fn new() -> Self {
-        let gpu_monitor = gpu::GpuMonitor::new().ok();
+        Self::build(gpu::GpuMonitor::new().ok())
+    }
+
+    // 完全跳过 NVML 初始化，避免无 GPU 的服务器上的探测开销
+    pub fn without_gpu() -> Self {
+        Self::build(None)
+    }
+
+    fn build(gpu_monitor: Option<gpu::GpuMonitor>) -> Self {
         let mut sys = System::new_all();
         sys.refresh_all();
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
         Self {
             sys,
+            refresh_spec: RefreshSpec::all(),
             gpu_monitor,
             cached_gpu_stats: None,
-            last_gpu_update: std::time::Instant::now(),
+            last_gpu_update: None,
+            gpu_interval: DEFAULT_GPU_INTERVAL,
+            #[cfg(unix)]
+            last_disk_list_update: clock.now(),
+            clock,
             cpu_monitor: CpuMonitor::new(),
             memory_monitor: MemoryMonitor::new(),
             disk_monitor: DiskMonitor::new(),
             network_monitor: NetworkMonitor::new(),
+            connection_monitor: ConnectionMonitor::new(),
+            connections: false,
+            processes: true,
+            process_monitor: ProcessMonitor::new(),
+            entropy_monitor: None,
+            raid_monitor: None,
+            health_weights: HealthWeights::default(),
+            failures: HashMap::new(),
+        }
+    }
+
+    pub fn with_refresh_spec(mut self, spec: RefreshSpec) -> Self {
+        self.refresh_spec = spec;
+        self
+    }
+
+    pub fn with_gpu_interval(mut self, interval: Duration) -> Self {
+        self.gpu_interval = interval;
+        self
+    }
+
+    /// 替换计时所用的时钟（默认为系统时钟），GPU 缓存和挂载点列表从新时钟的当前时刻重新计时
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.last_gpu_update = None;
+        #[cfg(unix)]
+        {
+            self.last_disk_list_update = clock.now();
+        }
+        self.network_monitor = self.network_monitor.with_clock(Arc::clone(&clock));
+        self.clock = clock;
+        self
+    }
+
+    pub fn with_cpu_smoothing(mut self, alpha: f32) -> Self {
+        self.cpu_monitor = self.cpu_monitor.with_smoothing(alpha);
+        self
+    }
+
+    pub fn with_host_memory(mut self, host_memory: bool) -> Self {
+        self.memory_monitor = self.memory_monitor.with_host_memory(host_memory);
+        self
+    }
+
+    pub fn with_disk_dedup(mut self, dedup: bool) -> Self {
+        self.disk_monitor = self.disk_monitor.with_dedup(dedup);
+        self
+    }
+
+    pub fn with_disk_filter(mut self, all_disks: bool, exclude: Vec<String>) -> Self {
+        self.disk_monitor = self.disk_monitor.with_filter(all_disks, exclude);
+        self
+    }
+
+    pub fn with_disk_sort(mut self, sort: DiskSort) -> Self {
+        self.disk_monitor = self.disk_monitor.with_sort(sort);
+        self
+    }
+
+    pub fn with_net_aggregate(mut self, aggregate: bool) -> Self {
+        self.network_monitor = self.network_monitor.with_aggregate(aggregate);
+        self
+    }
+
+    pub fn with_net_window(mut self, window: usize) -> Self {
+        self.network_monitor = self.network_monitor.with_window(window);
+        self
+    }
+
+    pub fn with_net_filter(mut self, include: Vec<String>, exclude: Vec<String>) -> Self {
+        self.network_monitor = self.network_monitor.with_filter(include, exclude);
+        self
+    }
+
+    pub fn with_process_grouping(mut self, group: bool) -> Self {
+        self.process_monitor = self.process_monitor.with_grouping(group);
+        self
+    }
+
+    pub fn with_entropy(mut self, enabled: bool) -> Self {
+        self.entropy_monitor = enabled.then(EntropyMonitor::new);
+        self
+    }
+
+    pub fn with_connections(mut self, enabled: bool) -> Self {
+        self.connections = enabled;
+        self
+    }
+
+    /// 是否采集进程表（默认采集）。进程表是每个周期最贵的一项，不显示进程的输出应关闭
+    pub fn with_processes(mut self, enabled: bool) -> Self {
+        self.processes = enabled;
+        self
+    }
+
+    pub fn with_raid(mut self, enabled: bool) -> Self {
+        self.raid_monitor = enabled.then(RaidMonitor::new);
+        self
+    }
+
+    pub fn with_health_weights(mut self, weights: HealthWeights) -> Self {
+        self.health_weights = weights;
+        self
+    }
+
+    pub fn has_gpu(&self) -> bool {
+        self.gpu_monitor.is_some()
+    }
+
+    // litemon 自身的常驻内存（字节）；单独刷新自身进程，进程表未刷新时读数也是最新的
+    pub fn self_memory(&mut self) -> Option<u64> {
+        let pid = sysinfo::get_current_pid().ok()?;
+        if !self.sys.refresh_process(pid) {
+            return None;
+        }
+        self.sys.process(pid).map(|process| process.memory())
+    }
+
+    // CPU 使用率和网络速率都依赖两次采样之差：启动时先采一次基线，
+    // 等待 duration 后再刷新，使第一次展示的数据基于真实的前一次采样。
+    // 不预热时仍查询一次 GPU，--once 等单次输出紧接着采样，不会经过 refresh
+    pub fn warmup(&mut self, duration: Duration) {
+        if duration.is_zero() {
+            self.refresh_gpu();
+            return;
+        }
+        self.refresh_sys();
+        let _ = self.network_stats();
+        let _ = self.disk_stats();
+        if self.processes {
+            let _ = self.process_stats(0);
+        }
+        std::thread::sleep(duration);
+        self.refresh();
+    }
+
+    // 暂停期间不刷新，恢复时重新采一次基线，
+    // 避免把整个暂停时长当作一次采样间隔来计算 CPU、网络和磁盘速率
+    pub fn resume(&mut self) {
+        self.refresh_sys();
+        self.network_monitor.reset_baseline(&self.sys);
+        let _ = self.disk_stats();
+        if self.processes {
+            let _ = self.process_stats(0);
         }
     }
 
     pub fn refresh(&mut self) {
-        self.sys.refresh_all();
-        
-        if let Some(gpu) = &self.gpu_monitor {
-            if self.last_gpu_update.elapsed() >= std::time::Duration::from_secs(1) {
-                self.cached_gpu_stats = gpu.collect_stats().ok();
-                self.last_gpu_update = std::time::Instant::now();
+        self.refresh_sys();
+        self.refresh_gpu();
+    }
+
+    // GPU 查询较慢，结果缓存 gpu_interval；第一次刷新时立即查询，单次输出和启动后的首屏也有 GPU 数据
+    fn refresh_gpu(&mut self) {
+        let Some(gpu) = &self.gpu_monitor else {
+            return;
+        };
+        let now = self.clock.now();
+        if self.last_gpu_update.is_some_and(|last| now.duration_since(last) < self.gpu_interval) {
+            return;
+        }
+        let result = gpu.collect_stats();
+        self.cached_gpu_stats = self.track(Subsystem::Gpu, result);
+        self.last_gpu_update = Some(now);
+    }
+
+    // 不使用 refresh_all：sysinfo 刷新磁盘时会对每个挂载点同步 statvfs，
+    // 失效的 NFS 挂载会卡住整个程序。Unix 下磁盘容量由 DiskMonitor 带超时探测。
+    // 全部启用时走原来的 refresh_system，否则只刷新启用项需要的部分，
+    // 进程表很大的机器上关闭 CPU 和内存可以省掉最贵的进程刷新
+    fn refresh_sys(&mut self) {
+        let spec = self.refresh_spec;
+        if spec.is_all() {
+            self.sys.refresh_system();
+        } else {
+            if spec.cpu {
+                self.sys.refresh_cpu();
+                self.sys.refresh_components();  // CPU 温度
+            }
+            if spec.memory {
+                self.sys.refresh_memory();
+            }
+        }
+        // 连接表按 pid 从进程表查进程名，启用 --connections 时同样需要刷新
+        if spec.processes() && (self.processes || self.connections) {
+            self.sys.refresh_processes();
+        }
+        if spec.network {
+            self.sys.refresh_networks();
+        }
+        #[cfg(not(unix))]
+        if spec.disk {
+            self.sys.refresh_disks();
+        }
+        // 定期重新列举挂载点以发现新挂载和已卸载的磁盘；sysinfo 列举时跳过 NFS，不会因失效挂载卡住
+        #[cfg(unix)]
+        if spec.disk {
+            let now = self.clock.now();
+            if now.duration_since(self.last_disk_list_update) >= DISK_LIST_INTERVAL {
+                self.sys.refresh_disks_list();
+                self.last_disk_list_update = now;
             }
         }
     }
@@ -54,20 +398,38 @@ impl Monitor {
         self.cpu_monitor.collect_stats(&self.sys)
     }
 
-    pub fn cpu_info(&self) -> String {
-        let info = self.sys.global_cpu_info();
-        format!(
-            "{}\n频率: {:.1} GHz",
-            info.brand(),
-            info.frequency() as f64 / 1000.0
-        )
+    pub fn cpu_info(&self) -> CpuInfo {
+        self.cpu_monitor.cpu_info(&self.sys)
+    }
+
+    pub fn uptime(&self) -> Duration {
+        Duration::from_secs(self.sys.uptime())
+    }
+
+    pub fn boot_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.sys.boot_time())
+    }
+
+    pub fn host_name(&self) -> Option<String> {
+        self.sys.host_name()
+    }
+
+    // Windows 没有平均负载的概念，sysinfo 在该平台返回全 0
+    #[cfg(unix)]
+    pub fn load_average(&self) -> Option<LoadAverage> {
+        Some(self.sys.load_average().into())
+    }
+
+    #[cfg(not(unix))]
+    pub fn load_average(&self) -> Option<LoadAverage> {
+        None
     }
 
     pub fn memory_stats(&self) -> Result<MemoryStats> {
         self.memory_monitor.collect_stats(&self.sys)
     }
 
-    pub fn disk_stats(&self) -> Result<Vec<DiskStats>> {
+    pub fn disk_stats(&mut self) -> Result<Vec<DiskStats>> {
         self.disk_monitor.collect_stats(&self.sys)
     }
 
@@ -75,11 +437,425 @@ impl Monitor {
         self.network_monitor.collect_stats(&self.sys)
     }
 
-    pub fn gpu_stats(&self) -> Result<gpu::GpuStats> {
+    // 按 CPU 降序返回前 limit 个进程，CPU 相同时按 PID 排列，刷新之间顺序不会跳动；
+    // 分组模式下为前 limit 个进程树的根
+    pub fn process_stats(&mut self, limit: usize) -> Result<Vec<ProcessStats>> {
+        let mut processes = self.process_monitor.collect_stats(&self.sys)?;
+        processes.sort_by(|a, b| ProcessSort::Cpu.compare(a, b));
+        processes.truncate(limit);
+        Ok(processes)
+    }
+
+    /// 全部 TCP 连接，已建立的在前；按 pid 从进程表补全进程名
+    pub fn connections(&mut self) -> Result<Vec<ConnectionStats>> {
+        let mut connections = self.connection_monitor.collect_connections()?;
+        for connection in &mut connections {
+            connection.process = connection.pid
+                .and_then(|pid| self.sys.process(Pid::from_u32(pid)))
+                .map(|process| process.name().to_string());
+        }
+        Ok(connections)
+    }
+
+    // 成功时清零连续失败次数，失败时加一并记下错误
+    fn track<T>(&mut self, subsystem: Subsystem, result: Result<T>) -> Option<T> {
+        let failures = self.failures.entry(subsystem).or_default();
+        match result {
+            Ok(value) => {
+                failures.consecutive = 0;
+                Some(value)
+            }
+            Err(e) => {
+                failures.consecutive = failures.consecutive.saturating_add(1);
+                failures.last_error = Some(e.to_string());
+                None
+            }
+        }
+    }
+
+    /// 子系统最近一次采集失败的原因，恢复后仍保留
+    pub fn last_error(&self, subsystem: Subsystem) -> Option<&str> {
+        self.failures.get(&subsystem)?.last_error.as_deref()
+    }
+
+    /// 连续失败达到 [`FAILURE_THRESHOLD`] 次的子系统及最近一次错误
+    pub fn unavailable(&self) -> BTreeMap<Subsystem, String> {
+        self.failures.iter()
+            .filter(|(_, failures)| failures.consecutive >= FAILURE_THRESHOLD)
+            .filter_map(|(subsystem, failures)| Some((*subsystem, failures.last_error.clone()?)))
+            .collect()
+    }
+
+    pub fn gpu_stats(&self) -> Result<GpuStats> {
         if let Some(stats) = &self.cached_gpu_stats {
             Ok(stats.clone())
         } else {
             Err(LiteMonError::NoGpuFound)
         }
     }
-} 
\ No newline at end of file
+
+    /// 不论启用了哪些监控项，对每个子系统采集一次并报告能否取得数据，用于排查缺失的指标。
+    /// 会刷新一次数据，应在预热之后、正常采样之前调用
+    pub fn capabilities(&mut self) -> Capabilities {
+        let spec = std::mem::take(&mut self.refresh_spec);  // 默认值即启用全部监控项
+        self.refresh();
+        self.refresh_spec = spec;
+
+        let mut capabilities = Capabilities::default();
+        let cpu = self.cpu_stats();
+        capabilities.check(tr(Text::CpuInfo), match &cpu {
+            Ok(cpu) => Ok(fill(Text::CapCores, &[&cpu.core_count.to_string(), &self.cpu_info().brand])),
+            Err(e) => Err(e.to_string()),
+        });
+        if let Ok(cpu) = &cpu {
+            capabilities.check(tr(Text::CapCpuFrequency), match cpu.average_frequency() {
+                0 => Err(tr(Text::CapFrequencyUnavailable).to_string()),
+                frequency => Ok(format!("{} MHz", frequency)),
+            });
+            capabilities.check(tr(Text::CapCpuTemperature), cpu.temperature
+                .map(|temperature| format!("{:.1}°C", temperature))
+                .ok_or_else(|| tr(Text::CapNoSensor).to_string()));
+        }
+        capabilities.check(tr(Text::CapLoadAverage), self.load_average()
+            .map(|load| format!("{:.2} {:.2} {:.2}", load.one, load.five, load.fifteen))
+            .ok_or_else(|| tr(Text::CapUnsupported).to_string()));
+
+        match self.memory_stats() {
+            Ok(memory) => {
+                capabilities.check(tr(Text::Memory), Ok(fill(Text::CapMemoryTotal, &[&memory.total.to_string()])));
+                capabilities.check(tr(Text::Swap), match memory.swap_total {
+                    0 => Err(tr(Text::Disabled).to_string()),
+                    total => Ok(fill(Text::CapSwapTotal, &[&total.to_string(), &memory.swap_devices.len().to_string()])),
+                });
+            }
+            Err(e) => capabilities.check(tr(Text::Memory), Err(e.to_string())),
+        }
+
+        capabilities.check(tr(Text::Disk), match self.disk_stats() {
+            Ok(disks) if disks.is_empty() => Err(tr(Text::CapNoMounts).to_string()),
+            Ok(disks) => {
+                let failed = disks.iter().filter(|disk| disk.error.is_some()).count();
+                Ok(fill(Text::CapMounts, &[&disks.len().to_string(), &failed.to_string()]))
+            }
+            Err(e) => Err(e.to_string()),
+        });
+        capabilities.check(tr(Text::CapDiskIo), if cfg!(target_os = "linux") {
+            std::fs::metadata("/proc/diskstats")
+                .map(|_| "/proc/diskstats".to_string())
+                .map_err(|e| e.to_string())
+        } else {
+            Err(tr(Text::CapUnsupported).to_string())
+        });
+
+        let networks = self.network_stats();
+        capabilities.check(tr(Text::CapNetwork), match &networks {
+            Ok(networks) if networks.is_empty() => Err(tr(Text::CapNoInterfaces).to_string()),
+            Ok(networks) => Ok(fill(Text::CapInterfaces, &[&networks.len().to_string()])),
+            Err(e) => Err(e.to_string()),
+        });
+        let addresses = networks.iter().flatten().map(|net| net.ip_addresses.len()).sum::<usize>();
+        capabilities.check(tr(Text::CapIpAddress), match addresses {
+            0 if NetworkMonitor::ADDRESSES_SUPPORTED => Err(tr(Text::CapNoAddresses).to_string()),
+            0 => Err(tr(Text::CapUnsupported).to_string()),
+            count => Ok(fill(Text::CapAddresses, &[&count.to_string()])),
+        });
+        capabilities.check(tr(Text::TcpConnections), self.connection_monitor.collect_summary()
+            .map(|tcp| fill(Text::CapEstablished, &[&tcp.established.to_string()]))
+            .map_err(|e| e.to_string()));
+
+        capabilities.check(tr(Text::Process), self.process_stats(usize::MAX)
+            .map(|processes| fill(Text::CapProcesses, &[&processes.len().to_string()]))
+            .map_err(|e| e.to_string()));
+        // 不受 --gpu-interval 缓存影响，直接查询一次；失败时报告后端的原始错误
+        capabilities.check(tr(Text::GpuInfo), if self.gpu_monitor.is_some() {
+            self.last_gpu_update = None;
+            self.refresh_gpu();
+            match &self.cached_gpu_stats {
+                Some(gpu) => Ok(gpu.name.clone()),
+                None => Err(self.last_error(Subsystem::Gpu)
+                    .map_or_else(|| LiteMonError::NoGpuFound.to_string(), str::to_string)),
+            }
+        } else {
+            Err(tr(Text::CapNoGpu).to_string())
+        });
+        capabilities.check(tr(Text::EntropyPool), EntropyMonitor::new().collect_stats()
+            .map(|entropy| format!("{} / {} bits", entropy.available, entropy.pool_size))
+            .map_err(|e| e.to_string()));
+        capabilities.check("RAID", RaidMonitor::new().collect_stats()
+            .map(|arrays| fill(Text::CapRaidArrays, &[&arrays.len().to_string()]))
+            .map_err(|e| e.to_string()));
+        capabilities
+    }
+
+    // 每个刷新周期只应调用一次：网络速率依赖两次采集之间的间隔
+    pub fn sample(&mut self) -> Sample {
+        let spec = self.refresh_spec;
+        let cpu = if spec.cpu {
+            let result = self.cpu_stats();
+            self.track(Subsystem::Cpu, result)
+        } else {
+            None
+        };
+        let memory = if spec.memory {
+            let result = self.memory_stats();
+            self.track(Subsystem::Memory, result)
+        } else {
+            None
+        };
+        let disks = if spec.disk {
+            let result = self.disk_stats();
+            self.track(Subsystem::Disk, result).unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        let networks = if spec.network {
+            let result = self.network_stats();
+            self.track(Subsystem::Network, result).unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        let processes = if spec.processes() && self.processes {
+            self.process_stats(usize::MAX).unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        let health = match (&cpu, &memory) {
+            (Some(cpu), Some(memory)) => {
+                Some(HealthScore::compute(cpu, memory, &disks, &self.health_weights))
+            }
+            _ => None,
+        };
+
+        Sample {
+            schema_version: SCHEMA_VERSION,
+            timestamp: SystemTime::now(),
+            cpu_info: self.cpu_info(),
+            uptime: self.uptime().as_secs(),
+            boot_time: self.boot_time().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()),
+            cpu,
+            load_average: self.load_average(),
+            memory,
+            disks,
+            networks,
+            tcp: if spec.network { self.connection_monitor.collect_summary().ok() } else { None },
+            gpu: self.gpu_stats().ok(),
+            health,
+            entropy: self.entropy_monitor.as_ref().and_then(|m| m.collect_stats().ok()),
+            raid: self.raid_monitor.as_ref().and_then(|m| m.collect_stats().ok()),
+            unavailable: self.unavailable(),
+            connections: if self.connections { self.connections().ok() } else { None },
+            processes,
+        }
+    }
+}
+
+#[cfg(test)]
+impl Sample {
+    // 各项均未采集的采样，采样时刻为 Unix 纪元，供各模块的测试按需填充
+    pub(crate) fn empty() -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            timestamp: UNIX_EPOCH,
+            cpu_info: CpuInfo {
+                brand: String::new(),
+                vendor: String::new(),
+                frequency: 0,
+                family: None,
+                model: None,
+                l1d_cache: None,
+                l1i_cache: None,
+                l2_cache: None,
+                l3_cache: None,
+            },
+            uptime: 0,
+            boot_time: 0,
+            cpu: None,
+            load_average: None,
+            memory: None,
+            disks: Vec::new(),
+            networks: Vec::new(),
+            tcp: None,
+            gpu: None,
+            health: None,
+            entropy: None,
+            raid: None,
+            unavailable: BTreeMap::new(),
+            connections: None,
+            processes: Vec::new(),
+        }
+    }
+}
+
+// 采样时刻序列化为 Unix 毫秒时间戳，便于下游直接计算
+pub(crate) fn serialize_timestamp<S: Serializer>(
+    timestamp: &SystemTime,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    let millis = timestamp
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+    serializer.serialize_u64(millis)
+}
+
+pub(crate) fn deserialize_timestamp<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<SystemTime, D::Error> {
+    let millis = u64::deserialize(deserializer)?;
+    Ok(UNIX_EPOCH + Duration::from_millis(millis))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::clock::ManualClock;
+    use super::gpu::GpuBackend;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    // 每次查询的利用率依次为 1、2、3…，据此判断是否命中缓存
+    struct CountingBackend {
+        queries: Arc<AtomicU32>,
+    }
+
+    impl GpuBackend for CountingBackend {
+        fn collect(&self) -> Result<GpuStats> {
+            let utilization = self.queries.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(GpuStats {
+                name: "test".to_string(),
+                utilization,
+                memory_used: 0,
+                memory_total: 0,
+                temperature: 0,
+                throttle_reasons: Vec::new(),
+                fan_speed_percent: None,
+                power_usage_watts: None,
+                timestamp: SystemTime::now(),
+            })
+        }
+    }
+
+    fn monitor(clock: &Arc<ManualClock>, interval: Duration) -> (Monitor, Arc<AtomicU32>) {
+        let queries = Arc::new(AtomicU32::new(0));
+        let backend = CountingBackend { queries: Arc::clone(&queries) };
+        let monitor = Monitor::build(Some(gpu::GpuMonitor::with_backend(Box::new(backend))))
+            .with_refresh_spec(RefreshSpec { cpu: false, memory: false, disk: false, network: false })
+            .with_gpu_interval(interval)
+            .with_clock(Arc::clone(clock) as Arc<dyn Clock>);
+        (monitor, queries)
+    }
+
+    fn utilization(monitor: &Monitor) -> Option<u32> {
+        monitor.gpu_stats().ok().map(|gpu| gpu.utilization)
+    }
+
+    #[test]
+    fn gpu_cache_holds_until_interval() {
+        let clock = Arc::new(ManualClock::new());
+        let (mut monitor, queries) = monitor(&clock, Duration::from_secs(2));
+
+        monitor.refresh();
+        assert_eq!(queries.load(Ordering::SeqCst), 1);
+        assert_eq!(utilization(&monitor), Some(1));
+
+        clock.advance(Duration::from_millis(1_999));
+        monitor.refresh();
+        assert_eq!(queries.load(Ordering::SeqCst), 1);
+        assert_eq!(utilization(&monitor), Some(1));
+
+        clock.advance(Duration::from_millis(1));
+        monitor.refresh();
+        assert_eq!(queries.load(Ordering::SeqCst), 2);
+        assert_eq!(utilization(&monitor), Some(2));
+
+        clock.advance(Duration::from_secs(1));
+        monitor.refresh();
+        monitor.refresh();
+        assert_eq!(utilization(&monitor), Some(2));
+
+        clock.advance(Duration::from_secs(1));
+        monitor.refresh();
+        assert_eq!(queries.load(Ordering::SeqCst), 3);
+        assert_eq!(utilization(&monitor), Some(3));
+    }
+
+    #[test]
+    fn gpu_cache_restarts_from_new_clock() {
+        let clock = Arc::new(ManualClock::new());
+        let (mut monitor, queries) = monitor(&clock, Duration::from_secs(1));
+        clock.advance(Duration::from_secs(5));
+        monitor.refresh();
+        monitor.refresh();
+        assert_eq!(queries.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn gpu_is_queried_without_warmup() {
+        let clock = Arc::new(ManualClock::new());
+        let (mut monitor, queries) = monitor(&clock, Duration::from_secs(1));
+        monitor.warmup(Duration::ZERO);
+        assert_eq!(queries.load(Ordering::SeqCst), 1);
+        assert_eq!(monitor.sample().gpu.map(|gpu| gpu.utilization), Some(1));
+    }
+
+    #[test]
+    fn first_refresh_queries_despite_long_interval() {
+        let clock = Arc::new(ManualClock::new());
+        let (mut monitor, queries) = monitor(&clock, Duration::from_secs(10));
+        monitor.refresh();
+        assert_eq!(queries.load(Ordering::SeqCst), 1);
+        assert_eq!(utilization(&monitor), Some(1));
+
+        clock.advance(Duration::from_secs(9));
+        monitor.refresh();
+        assert_eq!(queries.load(Ordering::SeqCst), 1);
+    }
+
+    // 后端报错时 --capabilities 应给出原始错误，而不是“未找到 GPU”
+    struct FailingBackend;
+
+    impl GpuBackend for FailingBackend {
+        fn collect(&self) -> Result<GpuStats> {
+            Err(LiteMonError::Config("driver mismatch".to_string()))
+        }
+    }
+
+    #[test]
+    fn capabilities_report_gpu_errors() {
+        let clock = Arc::new(ManualClock::new());
+        let (mut monitor, queries) = monitor(&clock, Duration::from_secs(10));
+        monitor.refresh();
+        let report = monitor.capabilities().to_string();
+        assert_eq!(queries.load(Ordering::SeqCst), 2);
+        assert!(report.contains("test"));
+
+        let mut monitor = Monitor::build(Some(gpu::GpuMonitor::with_backend(Box::new(FailingBackend))));
+        let report = monitor.capabilities().to_string();
+        assert!(report.contains("driver mismatch"));
+    }
+
+    #[test]
+    fn processes_are_skipped_when_not_displayed() {
+        let mut monitor = Monitor::build(None).with_processes(false);
+        monitor.refresh();
+        assert!(monitor.sample().processes.is_empty());
+
+        let mut monitor = Monitor::build(None);
+        monitor.refresh();
+        assert!(!monitor.sample().processes.is_empty());
+    }
+
+    #[test]
+    fn self_memory_without_process_refresh() {
+        let clock = Arc::new(ManualClock::new());
+        let (mut monitor, _) = monitor(&clock, Duration::from_secs(1));
+        monitor.refresh();
+        assert!(monitor.self_memory().is_some_and(|memory| memory > 0));
+    }
+
+    #[test]
+    fn tcp_summary_follows_network_spec() {
+        let clock = Arc::new(ManualClock::new());
+        let (mut monitor, _) = monitor(&clock, Duration::from_secs(1));
+        assert!(monitor.sample().tcp.is_none());
+    }
+}
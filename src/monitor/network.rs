@@ -1,48 +1,123 @@
 use sysinfo::{NetworkExt, System, SystemExt};
+use serde::{Deserialize, Serialize};
+use crate::cli::UnitStyle;
 use crate::error::Result;
-use std::collections::HashMap;
-use std::time::Instant;
+use crate::i18n::{Text, tr};
+use crate::util::glob_match;
+use super::clock::{Clock, SystemClock};
+use super::memory::MemoryMonitor;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::{Instant, SystemTime};
 
-#[derive(Debug, Clone)]
+// 默认按最近 3 个采样间隔计算速率，单次刷新延迟不会让速率骤降
+const DEFAULT_WINDOW: usize = 3;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkStats {
     pub interface_name: String,
     pub received_bytes: u64,
     pub total_received: u64,
     pub transmitted_bytes: u64,
     pub total_transmitted: u64,
+    /// 最近若干次采样的平均速率（字节/秒）
+    pub average_received_bytes: u64,
+    pub average_transmitted_bytes: u64,
+    /// 累计包数和错误数，用于排查丢包、错误的网卡
+    #[serde(default)]
+    pub packets_received: u64,
+    #[serde(default)]
+    pub packets_transmitted: u64,
+    #[serde(default)]
+    pub errors_on_received: u64,
+    #[serde(default)]
+    pub errors_on_transmitted: u64,
+    /// 接口上配置的 IP 地址，IPv4 在前、IPv6 在后
+    #[serde(default)]
+    pub ip_addresses: Vec<String>,
+    #[serde(serialize_with = "super::serialize_timestamp", deserialize_with = "super::deserialize_timestamp")]
+    pub timestamp: SystemTime,  // 采样时刻
+    /// 汇总模式下归入该接口的子接口
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<NetworkStats>,
 }
 
 pub struct NetworkMonitor {
-    previous_stats: HashMap<String, NetworkStats>,
-    last_update: Instant,
+    counters: HashMap<String, VecDeque<(Instant, u64, u64)>>,  // 最近的 (时刻, 累计接收, 累计发送)
+    window: usize,  // 计算速率跨越的采样间隔数
+    history: HashMap<String, VecDeque<(u64, u64)>>,  // 最近的 (接收, 发送) 速率
+    aggregate: bool,  // 将子接口汇总到父接口
+    include: Vec<String>,  // 网卡名通配符，为空表示全部显示
+    exclude: Vec<String>,
+    clock: Arc<dyn Clock>,  // 计算速率间隔所用的时钟
+}
+
+impl Default for NetworkMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl NetworkMonitor {
     pub fn new() -> Self {
         Self {
-            previous_stats: HashMap::new(),
-            last_update: Instant::now(),
+            counters: HashMap::new(),
+            window: DEFAULT_WINDOW,
+            history: HashMap::new(),
+            aggregate: false,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            clock: Arc::new(SystemClock),
         }
     }
 
+    pub fn with_window(mut self, window: usize) -> Self {
+        self.window = window.max(1);
+        self
+    }
+
+    pub fn with_aggregate(mut self, aggregate: bool) -> Self {
+        self.aggregate = aggregate;
+        self
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn with_filter(mut self, include: Vec<String>, exclude: Vec<String>) -> Self {
+        self.include = include;
+        self.exclude = exclude;
+        self
+    }
+
+    // 排除优先：同时匹配两边的网卡不显示
+    fn is_shown(&self, interface_name: &str) -> bool {
+        let matches = |patterns: &[String]| patterns.iter().any(|p| glob_match(p, interface_name));
+        (self.include.is_empty() || matches(&self.include)) && !matches(&self.exclude)
+    }
+
     pub fn collect_stats(&mut self, sys: &System) -> Result<Vec<NetworkStats>> {
         let mut current_stats = Vec::new();
-        let now = Instant::now();
-        let interval = now.duration_since(self.last_update).as_secs_f64();
-        
+        let now = self.clock.now();
+        let timestamp = SystemTime::now();
+        let mut addresses = interface_addresses();
+        let mut present = HashSet::new();
+
         for (interface_name, data) in sys.networks() {
-            let previous = self.previous_stats.get(interface_name);
-            let received_bytes = if let Some(prev) = previous {
-                Self::calculate_speed(data.received(), prev.received_bytes, interval)
-            } else {
-                0.0
-            } as u64;
+            if !self.is_shown(interface_name) {
+                continue;
+            }
+            present.insert(interface_name.as_str());
+            let (received_bytes, transmitted_bytes) =
+                self.update_rates(interface_name, now, data.total_received(), data.total_transmitted());
+            let history = &self.history[interface_name.as_str()];
+            let samples = history.len() as u64;
 
-            let transmitted_bytes = if let Some(prev) = previous {
-                Self::calculate_speed(data.transmitted(), prev.transmitted_bytes, interval)
-            } else {
-                0.0
-            } as u64;
+            let mut ip_addresses = addresses.remove(interface_name.as_str()).unwrap_or_default();
+            ip_addresses.sort_by_key(IpAddr::is_ipv6);
 
             let stats = NetworkStats {
                 interface_name: interface_name.to_string(),
@@ -50,19 +125,193 @@ impl NetworkMonitor {
                 total_received: data.total_received(),
                 transmitted_bytes,
                 total_transmitted: data.total_transmitted(),
+                average_received_bytes: history.iter().map(|(rx, _)| rx).sum::<u64>() / samples,
+                average_transmitted_bytes: history.iter().map(|(_, tx)| tx).sum::<u64>() / samples,
+                packets_received: data.total_packets_received(),
+                packets_transmitted: data.total_packets_transmitted(),
+                errors_on_received: data.total_errors_on_received(),
+                errors_on_transmitted: data.total_errors_on_transmitted(),
+                ip_addresses: ip_addresses.iter().map(IpAddr::to_string).collect(),
+                timestamp,
+                children: Vec::new(),
             };
 
-            current_stats.push(stats.clone());
-            self.previous_stats.insert(interface_name.to_string(), stats);
+            current_stats.push(stats);
         }
+        self.retain_interfaces(&present);
 
-        self.last_update = now;
+        if self.aggregate {
+            current_stats = Self::aggregate_by_parent(current_stats);
+        }
         Ok(current_stats)
     }
 
+    // 速率取窗口内最早与最新累计计数之差，除以两者之间的单调时钟间隔，
+    // 与刷新频率和 --interval 无关；某次刷新被推迟时，其影响被分摊到整个窗口。
+    // 返回 (接收, 发送) 速率，并记入该接口的速率历史
+    fn update_rates(&mut self, interface_name: &str, now: Instant, received: u64, transmitted: u64) -> (u64, u64) {
+        let counters = self.counters.entry(interface_name.to_string()).or_default();
+        counters.push_back((now, received, transmitted));
+        while counters.len() > self.window + 1 {
+            counters.pop_front();
+        }
+        let rates = match (counters.front(), counters.len()) {
+            (Some(&(since, rx, tx)), len) if len > 1 => {
+                let interval = now.duration_since(since).as_secs_f64();
+                (
+                    Self::calculate_speed(received, rx, interval) as u64,
+                    Self::calculate_speed(transmitted, tx, interval) as u64,
+                )
+            }
+            _ => (0, 0),
+        };
+
+        let history = self.history.entry(interface_name.to_string()).or_default();
+        if history.len() == super::AVERAGE_WINDOW {
+            history.pop_front();
+        }
+        history.push_back(rates);
+        rates
+    }
+
+    // 丢弃已消失接口（如拔出的 USB 网卡、销毁的容器 veth）的记录，避免长时间运行后无限增长
+    fn retain_interfaces(&mut self, present: &HashSet<&str>) {
+        self.counters.retain(|name, _| present.contains(name.as_str()));
+        self.history.retain(|name, _| present.contains(name.as_str()));
+    }
+
+    // 丢弃窗口内的累计计数，以当前读数作为新的基线，
+    // 下一次采集的速率只覆盖基线之后的间隔；历史平均值保留
+    pub fn reset_baseline(&mut self, sys: &System) {
+        let now = self.clock.now();
+        self.counters.clear();
+        for (interface_name, data) in sys.networks() {
+            if self.is_shown(interface_name) {
+                self.counters.insert(
+                    interface_name.to_string(),
+                    VecDeque::from([(now, data.total_received(), data.total_transmitted())]),
+                );
+            }
+        }
+    }
+
+    // Linux 的 lo、BSD/macOS 的 lo0、Windows 的 Loopback Pseudo-Interface
+    pub fn is_loopback(interface_name: &str) -> bool {
+        interface_name.strip_prefix("lo").is_some_and(|rest| rest.chars().all(|c| c.is_ascii_digit()))
+            || interface_name.to_lowercase().contains("loopback")
+    }
+
+    /// 所有接口的合计，名称为 "Total"。本机回环流量不经过网卡，不计入；
+    /// 只合计顶层接口，汇总模式下的子接口已包含在父接口中
+    pub fn aggregate(stats: &[NetworkStats]) -> NetworkStats {
+        let interfaces: Vec<&NetworkStats> = stats.iter()
+            .filter(|net| !Self::is_loopback(&net.interface_name))
+            .collect();
+        Self::sum("Total".to_string(), &interfaces)
+    }
+
+    // 各项计数相加，没有接口时取当前时刻作为采样时刻
+    fn sum(interface_name: String, stats: &[&NetworkStats]) -> NetworkStats {
+        let sum = |field: fn(&NetworkStats) -> u64| stats.iter().map(|net| field(net)).sum();
+        NetworkStats {
+            interface_name,
+            received_bytes: sum(|n| n.received_bytes),
+            total_received: sum(|n| n.total_received),
+            transmitted_bytes: sum(|n| n.transmitted_bytes),
+            total_transmitted: sum(|n| n.total_transmitted),
+            average_received_bytes: sum(|n| n.average_received_bytes),
+            average_transmitted_bytes: sum(|n| n.average_transmitted_bytes),
+            packets_received: sum(|n| n.packets_received),
+            packets_transmitted: sum(|n| n.packets_transmitted),
+            errors_on_received: sum(|n| n.errors_on_received),
+            errors_on_transmitted: sum(|n| n.errors_on_transmitted),
+            ip_addresses: Vec::new(),
+            timestamp: stats.first().map_or_else(SystemTime::now, |net| net.timestamp),
+            children: Vec::new(),
+        }
+    }
+
+    // 子接口名去掉 VLAN（.）、别名（:）和 veth 对端（@）后缀即为父接口名
+    fn parent_name(interface_name: &str) -> &str {
+        interface_name
+            .split(['.', ':', '@'])
+            .next()
+            .filter(|parent| !parent.is_empty())
+            .unwrap_or(interface_name)
+    }
+
+    // 按父接口分组。父接口存在时其计数已包含子接口流量，直接沿用；
+    // 只有子接口时将各项相加得到父接口的合计
+    fn aggregate_by_parent(stats: Vec<NetworkStats>) -> Vec<NetworkStats> {
+        let mut groups: Vec<(String, Option<NetworkStats>, Vec<NetworkStats>)> = Vec::new();
+
+        for net in stats {
+            let parent = Self::parent_name(&net.interface_name).to_string();
+            let index = match groups.iter().position(|(name, _, _)| *name == parent) {
+                Some(index) => index,
+                None => {
+                    groups.push((parent.clone(), None, Vec::new()));
+                    groups.len() - 1
+                }
+            };
+            let group = &mut groups[index];
+            if net.interface_name == parent {
+                group.1 = Some(net);
+            } else {
+                group.2.push(net);
+            }
+        }
+
+        groups.into_iter()
+            .map(|(name, parent, mut children)| {
+                children.sort_by(|a, b| a.interface_name.cmp(&b.interface_name));
+                let mut parent = parent.unwrap_or_else(|| {
+                    Self::sum(name, &children.iter().collect::<Vec<_>>())
+                });
+                parent.children = children;
+                parent
+            })
+            .collect()
+    }
+
+    pub fn has_errors(net: &NetworkStats) -> bool {
+        net.errors_on_received > 0 || net.errors_on_transmitted > 0
+    }
+
+    // 包数和错误数的明细
+    pub fn packet_summary(net: &NetworkStats) -> String {
+        format!(
+            "{}: ↓{} ↑{} │ {}: ↓{} ↑{}",
+            tr(Text::Packets),
+            net.packets_received,
+            net.packets_transmitted,
+            tr(Text::Errors),
+            net.errors_on_received,
+            net.errors_on_transmitted,
+        )
+    }
+
+    /// 当前平台能否取得接口地址，不能时界面不显示地址，而不是显示为无地址
+    pub const ADDRESSES_SUPPORTED: bool = cfg!(unix);
+
+    // IPv4 和 IPv6 地址分组显示
+    pub fn address_summary(net: &NetworkStats) -> String {
+        if net.ip_addresses.is_empty() {
+            return tr(Text::NoAddress).to_string();
+        }
+        let (v6, v4): (Vec<&str>, Vec<&str>) = net.ip_addresses.iter()
+            .map(String::as_str)
+            .partition(|address| address.contains(':'));
+        [("IPv4", v4), ("IPv6", v6)].into_iter()
+            .filter(|(_, addresses)| !addresses.is_empty())
+            .map(|(family, addresses)| format!("{}: {}", family, addresses.join(", ")))
+            .collect::<Vec<_>>()
+            .join(" │ ")
+    }
+
     // 计算传输速率（字节/秒）
     pub fn calculate_speed(current: u64, previous: u64, interval: f64) -> f64 {
-        if current >= previous {
+        if current >= previous && interval > 0.0 {
             (current - previous) as f64 / interval
         } else {
             0.0
@@ -70,19 +319,236 @@ impl NetworkMonitor {
     }
 
     // 格式化网络速率
-    pub fn format_speed(bytes_per_sec: f64) -> String {
-        const KB: f64 = 1024.0;
-        const MB: f64 = KB * 1024.0;
-        const GB: f64 = MB * 1024.0;
-
-        if bytes_per_sec >= GB {
-            format!("{:.2} GB/s", bytes_per_sec / GB)
-        } else if bytes_per_sec >= MB {
-            format!("{:.2} MB/s", bytes_per_sec / MB)
-        } else if bytes_per_sec >= KB {
-            format!("{:.2} KB/s", bytes_per_sec / KB)
+    pub fn format_speed(bytes_per_sec: f64, units: UnitStyle) -> String {
+        format!("{}/s", MemoryMonitor::format_scaled(bytes_per_sec, units))
+    }
+
+    // 网络设备通常以比特速率标称，按 1000 进位（Kbps/Mbps/Gbps）
+    pub fn format_speed_bits(bytes_per_sec: f64) -> String {
+        let bits = bytes_per_sec * 8.0;
+        if bits >= 1e9 {
+            format!("{:.2} Gbps", bits / 1e9)
+        } else if bits >= 1e6 {
+            format!("{:.2} Mbps", bits / 1e6)
+        } else if bits >= 1e3 {
+            format!("{:.2} Kbps", bits / 1e3)
         } else {
-            format!("{:.0} B/s", bytes_per_sec)
+            format!("{:.0} bps", bits)
+        }
+    }
+
+    // 网卡速率：--net-bits 时以比特/秒显示，否则与磁盘读写一样以字节/秒显示
+    pub fn format_net_speed(bytes_per_sec: f64, units: UnitStyle, bits: bool) -> String {
+        if bits {
+            Self::format_speed_bits(bytes_per_sec)
+        } else {
+            Self::format_speed(bytes_per_sec, units)
+        }
+    }
+}
+
+// sysinfo 0.29 不提供接口地址（其内部的 getifaddrs 只用于 MAC 地址），通过 getifaddrs 按接口名收集
+#[cfg(unix)]
+fn interface_addresses() -> HashMap<String, Vec<IpAddr>> {
+    use std::ffi::CStr;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    let mut addresses: HashMap<String, Vec<IpAddr>> = HashMap::new();
+    let mut list: *mut libc::ifaddrs = std::ptr::null_mut();
+    if unsafe { libc::getifaddrs(&mut list) } != 0 {
+        return addresses;
+    }
+
+    let mut current = list;
+    while !current.is_null() {
+        let entry = unsafe { &*current };
+        current = entry.ifa_next;
+        if entry.ifa_addr.is_null() {
+            continue;
+        }
+        let address = match i32::from(unsafe { (*entry.ifa_addr).sa_family }) {
+            libc::AF_INET => {
+                let addr = unsafe { &*(entry.ifa_addr as *const libc::sockaddr_in) };
+                IpAddr::V4(Ipv4Addr::from(u32::from_be(addr.sin_addr.s_addr)))
+            }
+            libc::AF_INET6 => {
+                let addr = unsafe { &*(entry.ifa_addr as *const libc::sockaddr_in6) };
+                IpAddr::V6(Ipv6Addr::from(addr.sin6_addr.s6_addr))
+            }
+            _ => continue,
+        };
+        let name = unsafe { CStr::from_ptr(entry.ifa_name) }.to_string_lossy().into_owned();
+        addresses.entry(name).or_default().push(address);
+    }
+
+    unsafe { libc::freeifaddrs(list) };
+    addresses
+}
+
+#[cfg(not(unix))]
+fn interface_addresses() -> HashMap<String, Vec<IpAddr>> {
+    HashMap::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitor::clock::ManualClock;
+    use std::time::Duration;
+
+    fn monitor(clock: &Arc<ManualClock>) -> NetworkMonitor {
+        NetworkMonitor::new().with_clock(Arc::clone(clock) as Arc<dyn Clock>)
+    }
+
+    #[test]
+    fn filter_exclude_wins() {
+        let monitor = NetworkMonitor::new()
+            .with_filter(vec!["eth*".to_string(), "veth*".to_string()], vec!["veth*".to_string()]);
+        assert!(monitor.is_shown("eth0"));
+        assert!(!monitor.is_shown("veth1234"));
+        assert!(!monitor.is_shown("docker0"));
+    }
+
+    #[test]
+    fn filter_defaults_show_everything() {
+        let monitor = NetworkMonitor::new();
+        for name in ["lo", "eth0", "docker0", "veth1234"] {
+            assert!(monitor.is_shown(name), "{}", name);
         }
+        let monitor = NetworkMonitor::new().with_filter(Vec::new(), vec!["lo".to_string(), "docker*".to_string()]);
+        assert!(!monitor.is_shown("lo"));
+        assert!(!monitor.is_shown("docker0"));
+        assert!(monitor.is_shown("eth0"));
+    }
+
+    #[test]
+    fn rate_over_one_second() {
+        let clock = Arc::new(ManualClock::new());
+        let mut monitor = monitor(&clock);
+        assert_eq!(monitor.update_rates("eth0", clock.now(), 1_000, 500), (0, 0));
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(monitor.update_rates("eth0", clock.now(), 3_048, 1_524), (2_048, 1_024));
+    }
+
+    #[test]
+    fn rate_uses_elapsed_time_not_sample_count() {
+        let clock = Arc::new(ManualClock::new());
+        let mut monitor = monitor(&clock).with_window(1);
+        monitor.update_rates("eth0", clock.now(), 0, 0);
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(monitor.update_rates("eth0", clock.now(), 1_000, 0), (4_000, 0));
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(monitor.update_rates("eth0", clock.now(), 5_000, 0), (2_000, 0));
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn rate_spans_the_window() {
+        let clock = Arc::new(ManualClock::new());
+        let mut monitor = monitor(&clock).with_window(3);
+        for received in [0, 1_000, 1_000, 4_000] {
+            monitor.update_rates("eth0", clock.now(), received, 0);
+            clock.advance(Duration::from_secs(1));
+        }
+        // 窗口为 (1s, 1000) 到 (4s, 7000)
+        assert_eq!(monitor.update_rates("eth0", clock.now(), 7_000, 0), (2_000, 0));
+    }
+
+    #[test]
+    fn speed_is_byte_delta_over_interval() {
+        assert_eq!(NetworkMonitor::calculate_speed(6_000, 1_000, 5.0), 1_000.0);
+        assert_eq!(NetworkMonitor::calculate_speed(1_500, 1_000, 0.5), 1_000.0);
+        assert_eq!(NetworkMonitor::calculate_speed(1_000, 1_000, 1.0), 0.0);
+        assert_eq!(NetworkMonitor::calculate_speed(2_000, 1_000, 0.0), 0.0);
+    }
+
+    // 速率与 --interval 无关，只取决于两次读数之间实际经过的时间
+    #[test]
+    fn rate_with_five_second_interval() {
+        let clock = Arc::new(ManualClock::new());
+        let mut monitor = monitor(&clock).with_window(1);
+        monitor.update_rates("eth0", clock.now(), 0, 0);
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(monitor.update_rates("eth0", clock.now(), 50_000, 5_000), (10_000, 1_000));
+    }
+
+    // 恒定 1000 B/s 的流量，刷新间隔忽长忽短（含一次 5 秒的卡顿），速率始终接近 1000
+    #[test]
+    fn irregular_intervals_stay_in_band() {
+        let clock = Arc::new(ManualClock::new());
+        let mut monitor = monitor(&clock);
+        let mut elapsed = 0;
+        monitor.update_rates("eth0", clock.now(), 0, 0);
+        for millis in [1_000, 200, 5_000, 300, 1_000, 50, 2_500, 1_000] {
+            clock.advance(Duration::from_millis(millis));
+            elapsed += millis;
+            let (received, _) = monitor.update_rates("eth0", clock.now(), elapsed, 0);
+            assert!((990..=1_010).contains(&received), "{} ms: {} B/s", millis, received);
+        }
+    }
+
+    // 刷新被推迟 10 秒时，速率仍按窗口内实际经过的时间计算，不会骤降
+    #[test]
+    fn delayed_refresh_is_spread_over_window() {
+        let clock = Arc::new(ManualClock::new());
+        let mut monitor = monitor(&clock).with_window(3);
+        let mut total = 0;
+        for _ in 0..4 {
+            monitor.update_rates("eth0", clock.now(), total, 0);
+            clock.advance(Duration::from_secs(1));
+            total += 1_000;
+        }
+        clock.advance(Duration::from_secs(9));
+        let (received, _) = monitor.update_rates("eth0", clock.now(), total + 9_000, 0);
+        assert_eq!(received, 1_000);
+    }
+
+    #[test]
+    fn counter_reset_gives_zero() {
+        let clock = Arc::new(ManualClock::new());
+        let mut monitor = monitor(&clock);
+        monitor.update_rates("eth0", clock.now(), 10_000, 10_000);
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(monitor.update_rates("eth0", clock.now(), 100, 100), (0, 0));
+    }
+
+    #[test]
+    fn average_over_history() {
+        let clock = Arc::new(ManualClock::new());
+        let mut monitor = monitor(&clock).with_window(1);
+        for received in [0, 1_000, 4_000] {
+            monitor.update_rates("eth0", clock.now(), received, 0);
+            clock.advance(Duration::from_secs(1));
+        }
+        let history = &monitor.history["eth0"];
+        assert_eq!(history.iter().map(|(rx, _)| rx).collect::<Vec<_>>(), [&0, &1_000, &3_000]);
+    }
+
+    #[test]
+    fn bits_are_eight_times_bytes_with_decimal_prefixes() {
+        assert_eq!(NetworkMonitor::format_speed_bits(0.0), "0 bps");
+        assert_eq!(NetworkMonitor::format_speed_bits(100.0), "800 bps");
+        assert_eq!(NetworkMonitor::format_speed_bits(125.0), "1.00 Kbps");
+        assert_eq!(NetworkMonitor::format_speed_bits(125_000.0), "1.00 Mbps");
+        assert_eq!(NetworkMonitor::format_speed_bits(12_500_000.0), "100.00 Mbps");
+        assert_eq!(NetworkMonitor::format_speed_bits(125_000_000.0), "1.00 Gbps");
+    }
+
+    #[test]
+    fn net_speed_follows_bits_flag() {
+        assert_eq!(NetworkMonitor::format_net_speed(125_000.0, UnitStyle::Decimal, true), "1.00 Mbps");
+        assert_eq!(NetworkMonitor::format_net_speed(125_000.0, UnitStyle::Decimal, false), "125.00 KB/s");
+        assert_eq!(NetworkMonitor::format_net_speed(2048.0, UnitStyle::Binary, false), "2.00 KiB/s");
+    }
+
+    #[test]
+    fn vanished_interfaces_are_pruned() {
+        let mut monitor = NetworkMonitor::new();
+        let now = Instant::now();
+        for name in ["eth0", "veth1a2b"] {
+            monitor.update_rates(name, now, 0, 0);
+        }
+        monitor.retain_interfaces(&HashSet::from(["eth0"]));
+        assert_eq!(monitor.counters.keys().collect::<Vec<_>>(), ["eth0"]);
+        assert_eq!(monitor.history.keys().collect::<Vec<_>>(), ["eth0"]);
+    }
+}
@@ -0,0 +1,311 @@
+use sysinfo::{Pid, PidExt, ProcessExt, System, SystemExt};
+use serde::{Deserialize, Serialize};
+use crate::error::Result;
+use crate::i18n::{Text, tr};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessStats {
+    pub pid: u32,
+    pub name: String,
+    pub cpu_usage: f32,       // 占单个核心的百分比，多线程进程可超过 100
+    pub memory: u64,
+    pub disk_read: u64,       // 读取速率（字节/秒）
+    pub disk_write: u64,      // 写入速率（字节/秒）
+    pub total_read: u64,
+    pub total_written: u64,
+    #[serde(default)]
+    pub parent_pid: Option<u32>,
+    /// 分组模式下的子进程；此时 CPU、内存和读写各项为包含全部子孙进程的合计
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<ProcessStats>,
+}
+
+/// 进程表的排序方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessSort {
+    Cpu,
+    Memory,
+    DiskRead,
+    DiskWrite,
+}
+
+impl ProcessSort {
+    pub fn next(self) -> Self {
+        match self {
+            ProcessSort::Cpu => ProcessSort::Memory,
+            ProcessSort::Memory => ProcessSort::DiskRead,
+            ProcessSort::DiskRead => ProcessSort::DiskWrite,
+            ProcessSort::DiskWrite => ProcessSort::Cpu,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ProcessSort::Cpu => "CPU",
+            ProcessSort::Memory => tr(Text::Memory),
+            ProcessSort::DiskRead => tr(Text::DiskRead),
+            ProcessSort::DiskWrite => tr(Text::DiskWrite),
+        }
+    }
+
+    // 降序比较，数值相同时按 PID 排列保证顺序稳定
+    pub fn compare(self, a: &ProcessStats, b: &ProcessStats) -> Ordering {
+        let ordering = match self {
+            ProcessSort::Cpu => b.cpu_usage.total_cmp(&a.cpu_usage),
+            ProcessSort::Memory => b.memory.cmp(&a.memory),
+            ProcessSort::DiskRead => b.disk_read.cmp(&a.disk_read),
+            ProcessSort::DiskWrite => b.disk_write.cmp(&a.disk_write),
+        };
+        ordering.then(a.pid.cmp(&b.pid))
+    }
+}
+
+pub struct ProcessMonitor {
+    previous_io: HashMap<Pid, (u64, u64)>,  // 上次扫描时的累计 (读取, 写入) 字节数
+    last_update: Instant,
+    group: bool,
+}
+
+impl Default for ProcessMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProcessMonitor {
+    pub fn new() -> Self {
+        Self {
+            previous_io: HashMap::new(),
+            last_update: Instant::now(),
+            group: false,
+        }
+    }
+
+    pub fn with_grouping(mut self, group: bool) -> Self {
+        self.group = group;
+        self
+    }
+
+    // 磁盘速率由两次扫描之间累计读写量之差计算，新出现的进程首次为 0
+    pub fn collect_stats(&mut self, sys: &System) -> Result<Vec<ProcessStats>> {
+        let now = Instant::now();
+        let interval = now.duration_since(self.last_update).as_secs_f64();
+        let mut current_io = HashMap::with_capacity(sys.processes().len());
+
+        let stats = sys.processes()
+            .iter()
+            .map(|(pid, process)| {
+                let usage = process.disk_usage();
+                let (disk_read, disk_write) = match self.previous_io.get(pid) {
+                    Some(&(read, written)) if interval > 0.0 => (
+                        (usage.total_read_bytes.saturating_sub(read) as f64 / interval) as u64,
+                        (usage.total_written_bytes.saturating_sub(written) as f64 / interval) as u64,
+                    ),
+                    _ => (0, 0),
+                };
+                current_io.insert(*pid, (usage.total_read_bytes, usage.total_written_bytes));
+
+                ProcessStats {
+                    pid: pid.as_u32(),
+                    name: process.name().to_string(),
+                    cpu_usage: process.cpu_usage(),
+                    memory: process.memory(),
+                    disk_read,
+                    disk_write,
+                    total_read: usage.total_read_bytes,
+                    total_written: usage.total_written_bytes,
+                    parent_pid: process.parent().map(|parent| parent.as_u32()),
+                    children: Vec::new(),
+                }
+            })
+            .collect();
+
+        // 只保留仍存活的进程，退出进程的缓存随之丢弃
+        self.previous_io = current_io;
+        self.last_update = now;
+        Ok(if self.group { Self::group_by_parent(stats) } else { stats })
+    }
+
+    /// 将进程按父子关系组织成树，父进程不在列表中的进程作为根。
+    /// 每个节点的 CPU、内存和读写各项累加其全部子孙进程，得到整组的真实占用
+    pub fn group_by_parent(processes: Vec<ProcessStats>) -> Vec<ProcessStats> {
+        let pids: HashSet<u32> = processes.iter().map(|process| process.pid).collect();
+        let mut children: HashMap<u32, Vec<ProcessStats>> = HashMap::new();
+        let mut roots = Vec::new();
+        for process in processes {
+            match process.parent_pid.filter(|parent| *parent != process.pid && pids.contains(parent)) {
+                Some(parent) => children.entry(parent).or_default().push(process),
+                None => roots.push(process),
+            }
+        }
+
+        let mut roots: Vec<ProcessStats> = roots.into_iter()
+            .map(|root| Self::attach_children(root, &mut children))
+            .collect();
+        // 父子关系成环时（进程号复用的瞬间）剩下的进程没有根，同样作为根输出
+        while let Some(&parent) = children.keys().next() {
+            for orphan in children.remove(&parent).unwrap_or_default() {
+                roots.push(Self::attach_children(orphan, &mut children));
+            }
+        }
+        roots
+    }
+
+    fn attach_children(mut process: ProcessStats, children: &mut HashMap<u32, Vec<ProcessStats>>) -> ProcessStats {
+        for child in children.remove(&process.pid).unwrap_or_default() {
+            let child = Self::attach_children(child, children);
+            process.cpu_usage += child.cpu_usage;
+            process.memory += child.memory;
+            process.disk_read += child.disk_read;
+            process.disk_write += child.disk_write;
+            process.total_read += child.total_read;
+            process.total_written += child.total_written;
+            process.children.push(child);
+        }
+        process
+    }
+
+    /// 按 sort 排序后深度优先展开进程树，返回 (层级, 进程)；未分组时全部为第 0 层
+    pub fn tree_rows(processes: &[ProcessStats], sort: ProcessSort) -> Vec<(usize, &ProcessStats)> {
+        let mut rows = Vec::new();
+        Self::push_rows(processes, sort, 0, &mut rows);
+        rows
+    }
+
+    fn push_rows<'a>(
+        processes: &'a [ProcessStats],
+        sort: ProcessSort,
+        depth: usize,
+        rows: &mut Vec<(usize, &'a ProcessStats)>,
+    ) {
+        let mut sorted: Vec<&ProcessStats> = processes.iter().collect();
+        sorted.sort_by(|a, b| sort.compare(a, b));
+        for process in sorted {
+            rows.push((depth, process));
+            Self::push_rows(&process.children, sort, depth + 1, rows);
+        }
+    }
+
+    /// 树形显示的进程名，子进程按层级缩进
+    pub fn tree_name(depth: usize, process: &ProcessStats) -> String {
+        match depth {
+            0 => process.name.clone(),
+            _ => format!("{}└ {}", "  ".repeat(depth - 1), process.name),
+        }
+    }
+
+    /// 进程最近一次运行所在的 CPU 核心（仅 Linux）。每次都要读一个文件，只对显示出来的行查询。
+    /// /proc/<pid>/stat 第 39 个字段为最近运行的处理器编号；
+    /// 进程名可能包含空格和括号，从最后一个 ')' 之后开始计数（其后为第 3 个字段）
+    #[cfg(target_os = "linux")]
+    pub fn last_cpu(pid: u32) -> Option<u32> {
+        let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+        let (_, rest) = stat.rsplit_once(')')?;
+        rest.split_whitespace().nth(39 - 3).and_then(|field| field.parse().ok())
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn last_cpu(_pid: u32) -> Option<u32> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, parent_pid: Option<u32>, name: &str, cpu_usage: f32, memory: u64) -> ProcessStats {
+        ProcessStats {
+            pid,
+            name: name.to_string(),
+            cpu_usage,
+            memory,
+            disk_read: 0,
+            disk_write: 0,
+            total_read: 0,
+            total_written: 0,
+            parent_pid,
+            children: Vec::new(),
+        }
+    }
+
+    // init(1) ─┬─ chrome(100) ─┬─ renderer(101) ── gpu(103)
+    //          │               └─ renderer(102)
+    //          └─ sshd(200)
+    // 另有父进程已退出的 orphan(300)
+    fn fixture() -> Vec<ProcessStats> {
+        vec![
+            process(101, Some(100), "renderer", 20.0, 200),
+            process(1, None, "init", 0.5, 10),
+            process(103, Some(101), "gpu", 5.0, 50),
+            process(100, Some(1), "chrome", 10.0, 300),
+            process(200, Some(1), "sshd", 1.0, 20),
+            process(102, Some(100), "renderer", 30.0, 100),
+            process(300, Some(299), "orphan", 2.0, 5),
+        ]
+    }
+
+    fn find(processes: &[ProcessStats], pid: u32) -> Option<&ProcessStats> {
+        processes.iter().find_map(|process| {
+            if process.pid == pid { Some(process) } else { find(&process.children, pid) }
+        })
+    }
+
+    #[test]
+    fn roots_are_processes_without_listed_parent() {
+        let tree = ProcessMonitor::group_by_parent(fixture());
+        let mut roots: Vec<u32> = tree.iter().map(|process| process.pid).collect();
+        roots.sort_unstable();
+        assert_eq!(roots, [1, 300]);
+    }
+
+    #[test]
+    fn totals_include_all_descendants() {
+        let tree = ProcessMonitor::group_by_parent(fixture());
+        let chrome = find(&tree, 100).unwrap();
+        assert_eq!(chrome.cpu_usage, 65.0);
+        assert_eq!(chrome.memory, 650);
+        assert_eq!(find(&tree, 101).unwrap().memory, 250);
+        let init = find(&tree, 1).unwrap();
+        assert_eq!(init.cpu_usage, 66.5);
+        assert_eq!(init.memory, 680);
+        assert_eq!(find(&tree, 300).unwrap().memory, 5);
+    }
+
+    #[test]
+    fn tree_rows_sorted_depth_first() {
+        let tree = ProcessMonitor::group_by_parent(fixture());
+        let rows: Vec<(usize, u32)> = ProcessMonitor::tree_rows(&tree, ProcessSort::Cpu)
+            .into_iter()
+            .map(|(depth, process)| (depth, process.pid))
+            .collect();
+        assert_eq!(rows, [(0, 1), (1, 100), (2, 102), (2, 101), (3, 103), (1, 200), (0, 300)]);
+    }
+
+    #[test]
+    fn cycle_does_not_lose_processes() {
+        let processes = vec![process(10, Some(11), "a", 1.0, 1), process(11, Some(10), "b", 1.0, 1)];
+        let tree = ProcessMonitor::group_by_parent(processes);
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].memory, 2);
+        assert!(find(&tree, 10).is_some() && find(&tree, 11).is_some());
+    }
+
+    #[test]
+    fn tree_name_indents_children() {
+        let child = process(2, Some(1), "child", 0.0, 0);
+        assert_eq!(ProcessMonitor::tree_name(0, &child), "child");
+        assert_eq!(ProcessMonitor::tree_name(1, &child), "└ child");
+        assert_eq!(ProcessMonitor::tree_name(3, &child), "    └ child");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn last_cpu_reads_proc_stat() {
+        assert!(ProcessMonitor::last_cpu(std::process::id()).is_some());
+        assert_eq!(ProcessMonitor::last_cpu(u32::MAX), None);
+    }
+}
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+use crate::error::Result;
+
+/// Linux 软件 RAID（md）阵列状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RaidStatus {
+    pub name: String,
+    pub level: String,       // raid1、raid5 等，未激活的阵列为空
+    pub state: String,       // active / inactive，只读时带括号后缀
+    pub active_disks: u32,
+    pub total_disks: u32,
+}
+
+impl RaidStatus {
+    // 阵列未激活，或工作中的成员盘少于应有数量，即失去了冗余
+    pub fn is_degraded(&self) -> bool {
+        !self.state.starts_with("active") || self.active_disks < self.total_disks
+    }
+}
+
+pub struct RaidMonitor;
+
+impl Default for RaidMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RaidMonitor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    // /proc/mdstat 中每个阵列占若干行：
+    //   md0 : active raid1 sdb1[1] sda1[0]
+    //         1048512 blocks super 1.2 [2/2] [UU]
+    // 首行给出状态和级别，随后的行中 [总数/在线数] 给出成员盘数量
+    #[cfg(target_os = "linux")]
+    pub fn collect_stats(&self) -> Result<Vec<RaidStatus>> {
+        // 未加载 md 驱动时没有该文件，视为没有阵列
+        let content = match std::fs::read_to_string("/proc/mdstat") {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut arrays: Vec<RaidStatus> = Vec::new();
+
+        for line in content.lines() {
+            if let Some((name, rest)) = line.split_once(" : ") {
+                let name = name.trim();
+                if !name.starts_with("md") {
+                    continue;
+                }
+                let mut words = rest.split_whitespace().peekable();
+                let mut state = words.next().unwrap_or_default().to_string();
+                if let Some(flag) = words.next_if(|word| word.starts_with('(')) {
+                    state = format!("{} {}", state, flag);
+                }
+                let level = words.next_if(|word| !word.contains('['))
+                    .unwrap_or_default()
+                    .to_string();
+                arrays.push(RaidStatus {
+                    name: name.to_string(),
+                    level,
+                    state,
+                    active_disks: 0,
+                    total_disks: 0,
+                });
+            } else if let Some(array) = arrays.last_mut() {
+                if let Some((total, active)) = Self::parse_disk_counts(line) {
+                    array.total_disks = total;
+                    array.active_disks = active;
+                }
+            }
+        }
+
+        Ok(arrays)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn collect_stats(&self) -> Result<Vec<RaidStatus>> {
+        Err(std::io::Error::from(std::io::ErrorKind::Unsupported).into())
+    }
+
+    // 查找形如 [3/2] 的片段
+    #[cfg(target_os = "linux")]
+    fn parse_disk_counts(line: &str) -> Option<(u32, u32)> {
+        line.split_whitespace()
+            .filter_map(|word| word.strip_prefix('[')?.strip_suffix(']'))
+            .find_map(|counts| {
+                let (total, active) = counts.split_once('/')?;
+                Some((total.parse().ok()?, active.parse().ok()?))
+            })
+    }
+}
@@ -0,0 +1,100 @@
+use crate::cli::UnitStyle;
+use crate::i18n::Text;
+use crate::monitor::Sample;
+use crate::template::{Field, Fields, Template};
+
+/// --oneline 的默认格式
+pub const DEFAULT_FORMAT: &str = "CPU {cpu} MEM {mem} DISK {disk} ↓{net_rx} ↑{net_tx}";
+
+// 可用占位符及其含义，取值已格式化为适合状态栏的短文本
+const FIELDS: &Fields = &[
+    ("cpu", Field::CpuTotal, Text::OnelineCpu),
+    ("mem", Field::MemUsedPct, Text::OnelineMem),
+    ("swap", Field::SwapUsedPct, Text::OnelineSwap),
+    ("disk", Field::DiskMaxPct, Text::OnelineDisk),
+    ("net_rx", Field::NetRx, Text::OnelineNetRx),
+    ("net_tx", Field::NetTx, Text::OnelineNetTx),
+    ("gpu", Field::GpuUtil, Text::OnelineGpu),
+];
+
+/// 单行状态栏格式，形如 `CPU {cpu} MEM {mem} ↓{net_rx}`
+///
+/// 语法与 `--format-string` 相同（同一个解析器，也支持 `:宽度.精度`），只是占位符更短，
+/// 取值输出为紧凑的文本：百分比默认不带小数，速率只用单个字母作单位。数据不可用时输出 `N/A`。
+#[derive(Debug, Clone)]
+pub struct OnelineFormat {
+    template: Template,
+}
+
+impl OnelineFormat {
+    pub fn parse(format: &str) -> Result<Self, String> {
+        Template::parse_fields(format, FIELDS).map(|template| Self { template })
+    }
+
+    pub fn render(&self, sample: &Sample, units: UnitStyle) -> String {
+        self.template.render_with(sample, |field, value, precision| match field {
+            Field::NetRx | Field::NetTx => compact_bytes(value, units),
+            _ => format!("{:.*}%", precision.unwrap_or(0), value),
+        })
+    }
+
+    // 用于 --help 的占位符说明
+    pub fn help() -> String {
+        Template::help_fields(FIELDS)
+    }
+}
+
+// 状态栏空间有限，只用单个字母作单位：300K、1.2M；小于 10 时保留 1 位小数
+fn compact_bytes(value: f64, units: UnitStyle) -> String {
+    let base = match units {
+        UnitStyle::Binary => 1024.0,
+        UnitStyle::Decimal => 1000.0,
+    };
+    let mut scaled = value;
+    let mut unit = "B";
+    for next in ["K", "M", "G", "T"] {
+        if scaled < base {
+            break;
+        }
+        scaled /= base;
+        unit = next;
+    }
+    if scaled < 10.0 && unit != "B" {
+        format!("{:.1}{}", scaled, unit)
+    } else {
+        format!("{:.0}{}", scaled, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitor::memory::MemoryStats;
+
+    fn render(format: &str, sample: &Sample) -> String {
+        OnelineFormat::parse(format).unwrap().render(sample, UnitStyle::Binary)
+    }
+
+    #[test]
+    fn compact_values() {
+        let sample = Sample { memory: Some(MemoryStats::with_usage(400, 100, 0, 0)), ..Sample::empty() };
+        assert_eq!(render(DEFAULT_FORMAT, &sample), "CPU N/A MEM 25% DISK N/A ↓0B ↑0B");
+        assert_eq!(render("{mem:.1} {swap}", &sample), "25.0% N/A");
+    }
+
+    #[test]
+    fn shares_template_syntax() {
+        let sample = Sample::empty();
+        assert_eq!(render("{{cpu}} {gpu:5}", &sample), "{cpu}   N/A");
+        assert!(OnelineFormat::parse("{cpu.total}").is_err());
+        assert!(OnelineFormat::parse("{cpu").is_err());
+        assert!(OnelineFormat::parse("cpu}").is_err());
+    }
+
+    #[test]
+    fn compact_bytes_units() {
+        assert_eq!(compact_bytes(512.0, UnitStyle::Binary), "512B");
+        assert_eq!(compact_bytes(1_258_291.0, UnitStyle::Binary), "1.2M");
+        assert_eq!(compact_bytes(300_000.0, UnitStyle::Decimal), "300K");
+    }
+}
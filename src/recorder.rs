@@ -0,0 +1,171 @@
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use crate::error::Result;
+use crate::monitor::Sample;
+use std::time::SystemTime;
+use crate::util::format_timestamp;
+
+// 固定列，之后每个网卡各占接收、发送两列（字节/秒）
+const FIXED_COLUMNS: [&str; 6] = ["timestamp", "cpu_pct", "mem_used", "mem_total", "swap_used", "swap_total"];
+
+/// CSV 记录器：每个采样周期向文件追加一行，便于之后用表格软件绘图。
+/// 列由文件的表头决定：新文件按第一次采样的网卡写表头，追加到已有文件时沿用原表头，
+/// 之后出现的新网卡不记录，消失的网卡留空，保证每行列数一致
+pub struct Recorder {
+    file: File,
+    interfaces: Option<Vec<String>>,  // 表头中的网卡，None 表示尚未写表头
+}
+
+impl Recorder {
+    pub fn open(path: &str) -> Result<Self> {
+        let interfaces = match File::open(path) {
+            Ok(existing) => BufReader::new(existing)
+                .lines()
+                .next()
+                .transpose()?
+                .map(|header| Self::parse_interfaces(&header)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self { file, interfaces })
+    }
+
+    pub fn record(&mut self, sample: &Sample) -> Result<()> {
+        if self.interfaces.is_none() {
+            let interfaces: Vec<String> = sample.networks.iter().map(|net| net.interface_name.clone()).collect();
+            self.write_header(&interfaces)?;
+            self.interfaces = Some(interfaces);
+        }
+        let interfaces = self.interfaces.as_deref().unwrap_or_default();
+
+        let mut row = vec![format_timestamp(sample.timestamp)];
+        row.push(sample.cpu.as_ref().map(|cpu| format!("{:.1}", cpu.total_usage)).unwrap_or_default());
+        match &sample.memory {
+            Some(mem) => row.extend([mem.used, mem.total, mem.swap_used, mem.swap_total].map(|v| v.to_string())),
+            None => row.extend(std::iter::repeat_n(String::new(), 4)),
+        }
+        for interface in interfaces {
+            match sample.networks.iter().find(|net| net.interface_name == *interface) {
+                Some(net) => {
+                    row.push(net.received_bytes.to_string());
+                    row.push(net.transmitted_bytes.to_string());
+                }
+                None => row.extend([String::new(), String::new()]),
+            }
+        }
+
+        writeln!(self.file, "{}", row.join(","))?;
+        Ok(())
+    }
+
+    /// 将 TUI 走势图的历史读数（采样时刻, 百分比）写成新的 CSV 文件，按时间从旧到新排列。
+    /// CPU 和内存按同一节奏记录，同一时刻的读数合并为一行，缺少的一项留空
+    pub fn write_history(path: &str, cpu: &[(SystemTime, u64)], memory: &[(SystemTime, u64)]) -> Result<()> {
+        let mut rows: BTreeMap<SystemTime, (Option<u64>, Option<u64>)> = BTreeMap::new();
+        for &(timestamp, value) in cpu {
+            rows.entry(timestamp).or_default().0 = Some(value);
+        }
+        for &(timestamp, value) in memory {
+            rows.entry(timestamp).or_default().1 = Some(value);
+        }
+
+        let mut file = File::create(path)?;
+        writeln!(file, "timestamp,cpu_pct,mem_pct")?;
+        for (timestamp, (cpu, memory)) in rows {
+            let [cpu, memory] = [cpu, memory].map(|value| value.map(|v| v.to_string()).unwrap_or_default());
+            writeln!(file, "{},{},{}", format_timestamp(timestamp), cpu, memory)?;
+        }
+        Ok(())
+    }
+
+    fn write_header(&mut self, interfaces: &[String]) -> Result<()> {
+        let mut columns: Vec<String> = FIXED_COLUMNS.iter().map(|c| c.to_string()).collect();
+        for interface in interfaces {
+            columns.push(quote(&format!("rx:{}", interface)));
+            columns.push(quote(&format!("tx:{}", interface)));
+        }
+        writeln!(self.file, "{}", columns.join(","))?;
+        Ok(())
+    }
+
+    fn parse_interfaces(header: &str) -> Vec<String> {
+        split_fields(header)
+            .into_iter()
+            .filter_map(|column| column.strip_prefix("rx:").map(str::to_string))
+            .collect()
+    }
+}
+
+// 含逗号、引号或换行的字段按 RFC 4180 用引号括起，内部引号写两次（Windows 网卡名可能含逗号）
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// 按逗号拆分一行，识别 quote 写出的带引号字段
+fn split_fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quotes_fields_with_separators() {
+        assert_eq!(quote("rx:eth0"), "rx:eth0");
+        assert_eq!(quote("rx:Wi-Fi, 2"), "\"rx:Wi-Fi, 2\"");
+        assert_eq!(quote("rx:a\"b"), "\"rx:a\"\"b\"");
+    }
+
+    #[test]
+    fn header_round_trips_interface_names() {
+        let interfaces = ["eth0", "Wi-Fi, 2", "say \"hi\""];
+        let mut sample = Sample::empty();
+        sample.networks = interfaces.iter()
+            .map(|name| serde_json::from_value(serde_json::json!({
+                "interface_name": name, "received_bytes": 1, "total_received": 0,
+                "transmitted_bytes": 2, "total_transmitted": 0,
+                "average_received_bytes": 0, "average_transmitted_bytes": 0, "timestamp": 0,
+            })).unwrap())
+            .collect();
+
+        let path = std::env::temp_dir().join(format!("litemon-recorder-{}.csv", std::process::id()));
+        let path = path.to_str().unwrap();
+        let _ = std::fs::remove_file(path);
+        Recorder::open(path).unwrap().record(&sample).unwrap();
+
+        let mut reopened = Recorder::open(path).unwrap();
+        assert_eq!(reopened.interfaces.as_deref().unwrap(), interfaces);
+        reopened.record(&sample).unwrap();
+
+        let content = std::fs::read_to_string(path).unwrap();
+        let _ = std::fs::remove_file(path);
+        let lines: Vec<Vec<String>> = content.lines().map(split_fields).collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].len(), FIXED_COLUMNS.len() + 2 * interfaces.len());
+        assert!(lines.iter().all(|fields| fields.len() == lines[0].len()));
+        assert_eq!(lines[0][FIXED_COLUMNS.len() + 2], "rx:Wi-Fi, 2");
+    }
+}
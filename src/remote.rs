@@ -0,0 +1,94 @@
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use crate::error::{Result, LiteMonError};
+use crate::i18n::{Text, fill, tr};
+use crate::monitor::Sample;
+
+// 单次拉取的连接和读写超时
+const FETCH_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// 远程主机的最新状态
+#[derive(Clone)]
+pub enum HostState {
+    Connecting,
+    Online(Box<Sample>),
+    Offline(String),  // 离线原因
+}
+
+/// 通过 --serve 暴露的 /sample 定期拉取一台远程 litemon 的采样，每台主机一个后台线程
+pub struct RemoteHost {
+    pub name: String,
+    state: Arc<Mutex<HostState>>,
+}
+
+impl RemoteHost {
+    pub fn spawn(name: String, addr: String, interval: Duration) -> Self {
+        let state = Arc::new(Mutex::new(HostState::Connecting));
+        let shared = Arc::clone(&state);
+
+        std::thread::spawn(move || loop {
+            let next = match Self::fetch(&addr) {
+                Ok(sample) => HostState::Online(Box::new(sample)),
+                Err(e) => HostState::Offline(e),
+            };
+            if let Ok(mut state) = shared.lock() {
+                *state = next;
+            }
+            std::thread::sleep(interval);
+        });
+
+        Self { name, state }
+    }
+
+    pub fn state(&self) -> HostState {
+        self.state.lock()
+            .map(|state| state.clone())
+            .unwrap_or_else(|_| HostState::Offline(tr(Text::RemoteStateUnavailable).to_string()))
+    }
+
+    fn fetch(addr: &str) -> std::result::Result<Sample, String> {
+        let socket = addr.to_socket_addrs()
+            .map_err(|e| e.to_string())?
+            .next()
+            .ok_or_else(|| fill(Text::RemoteUnresolved, &[addr]))?;
+        let mut stream = TcpStream::connect_timeout(&socket, FETCH_TIMEOUT).map_err(|e| e.to_string())?;
+        stream.set_read_timeout(Some(FETCH_TIMEOUT)).map_err(|e| e.to_string())?;
+        stream.set_write_timeout(Some(FETCH_TIMEOUT)).map_err(|e| e.to_string())?;
+
+        write!(stream, "GET /sample HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n", addr)
+            .map_err(|e| e.to_string())?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response).map_err(|e| e.to_string())?;
+
+        let (head, body) = response.split_once("\r\n\r\n").ok_or(tr(Text::RemoteBadResponse))?;
+        let status = head.lines().next().unwrap_or_default();
+        if !status.contains(" 200 ") {
+            return Err(status.to_string());
+        }
+        serde_json::from_str(body).map_err(|e| e.to_string())
+    }
+}
+
+/// 读取主机列表：每行一个 `地址` 或 `名称=地址`，空行和 # 开头的行忽略
+pub fn load_hosts(path: &str) -> Result<Vec<(String, String)>> {
+    let content = std::fs::read_to_string(path)?;
+    let hosts: Vec<(String, String)> = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| match line.split_once('=') {
+            Some((name, addr)) => (name.trim().to_string(), addr.trim().to_string()),
+            None => (line.to_string(), line.to_string()),
+        })
+        .collect();
+
+    if hosts.is_empty() {
+        return Err(LiteMonError::Io(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            fill(Text::RemoteNoHosts, &[path]),
+        )));
+    }
+    Ok(hosts)
+}
@@ -0,0 +1,162 @@
+use crate::cli::UnitStyle;
+use crate::i18n::{Text, fill, tr};
+use crate::monitor::{Sample, disk::DiskMonitor, network::NetworkMonitor};
+
+/// 一项指标的最小值、最大值和平均值，逐次累加，不保存历史读数
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Stat {
+    min: f64,
+    max: f64,
+    total: f64,
+    count: u64,
+}
+
+impl Stat {
+    pub fn update(&mut self, value: f64) {
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+        self.total += value;
+        self.count += 1;
+    }
+
+    /// (最小, 最大, 平均)，没有读数时为 None
+    pub fn finalize(&self) -> Option<(f64, f64, f64)> {
+        (self.count > 0).then(|| (self.min, self.max, self.total / self.count as f64))
+    }
+}
+
+/// 有限次数运行（--count）结束时的统计：CPU 和内存使用率的最小/最大/平均值，
+/// 以及网络合计（不含回环接口）的收发峰值速率，便于作为简单的基准测试工具
+#[derive(Debug, Default)]
+pub struct Summary {
+    samples: u64,
+    cpu: Stat,
+    memory: Stat,
+    peak_network: Option<(u64, u64)>,  // (接收, 发送) 峰值速率
+}
+
+impl Summary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, sample: &Sample) {
+        self.samples += 1;
+        if let Some(cpu) = &sample.cpu {
+            self.cpu.update(cpu.total_usage as f64);
+        }
+        if let Some(memory) = &sample.memory {
+            self.memory.update(DiskMonitor::usage_percentage(memory.total, memory.used));
+        }
+        if !sample.networks.is_empty() {
+            let total = NetworkMonitor::aggregate(&sample.networks);
+            let (received, transmitted) = self.peak_network.unwrap_or_default();
+            self.peak_network = Some((
+                received.max(total.received_bytes),
+                transmitted.max(total.transmitted_bytes),
+            ));
+        }
+    }
+
+    // 未采集的项不输出
+    pub fn lines(&self, units: UnitStyle, net_bits: bool) -> Vec<String> {
+        let mut lines = vec![fill(Text::Summary, &[&self.samples.to_string()])];
+        let stats = [(Text::CpuUsage, &self.cpu), (Text::MemoryUsage, &self.memory)];
+        for (name, stat) in stats {
+            if let Some((min, max, average)) = stat.finalize() {
+                let [min, max, average] = [min, max, average].map(|value| format!("{:.1}%", value));
+                lines.push(fill(Text::SummaryStat, &[tr(name), &min, &max, &average]));
+            }
+        }
+        if let Some((received, transmitted)) = self.peak_network {
+            lines.push(format!(
+                "{}: {} {}, {} {}",
+                tr(Text::NetworkPeak),
+                tr(Text::Receive),
+                NetworkMonitor::format_net_speed(received as f64, units, net_bits),
+                tr(Text::Transmit),
+                NetworkMonitor::format_net_speed(transmitted as f64, units, net_bits)
+            ));
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitor::memory::MemoryStats;
+    use crate::monitor::network::NetworkStats;
+    use std::time::SystemTime;
+
+    fn network(interface_name: &str, received_bytes: u64, transmitted_bytes: u64) -> NetworkStats {
+        NetworkStats {
+            interface_name: interface_name.to_string(),
+            received_bytes,
+            total_received: 0,
+            transmitted_bytes,
+            total_transmitted: 0,
+            average_received_bytes: 0,
+            average_transmitted_bytes: 0,
+            packets_received: 0,
+            packets_transmitted: 0,
+            errors_on_received: 0,
+            errors_on_transmitted: 0,
+            ip_addresses: Vec::new(),
+            timestamp: SystemTime::UNIX_EPOCH,
+            children: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn stat_without_values() {
+        assert_eq!(Stat::default().finalize(), None);
+    }
+
+    #[test]
+    fn stat_min_max_average() {
+        let mut stat = Stat::default();
+        for value in [40.0, 10.0, 70.0, 0.0] {
+            stat.update(value);
+        }
+        assert_eq!(stat.finalize(), Some((0.0, 70.0, 30.0)));
+    }
+
+    #[test]
+    fn stat_single_value() {
+        let mut stat = Stat::default();
+        stat.update(-5.0);
+        assert_eq!(stat.finalize(), Some((-5.0, -5.0, -5.0)));
+    }
+
+    #[test]
+    fn summary_accumulates_samples() {
+        let mut summary = Summary::new();
+        for (used, rx, tx) in [(20, 100, 900), (60, 700, 100), (40, 300, 300)] {
+            summary.update(&Sample {
+                memory: Some(MemoryStats::with_usage(100, used, 0, 0)),
+                networks: vec![network("eth0", rx, tx), network("lo", 1_000_000, 1_000_000)],
+                ..Sample::empty()
+            });
+        }
+        assert_eq!(summary.samples, 3);
+        assert_eq!(summary.cpu.finalize(), None);
+        assert_eq!(summary.memory.finalize(), Some((20.0, 60.0, 40.0)));
+        // 回环接口不计入峰值
+        assert_eq!(summary.peak_network, Some((700, 900)));
+    }
+
+    #[test]
+    fn summary_skips_missing_data() {
+        let mut summary = Summary::new();
+        summary.update(&Sample::empty());
+        assert_eq!(summary.samples, 1);
+        assert_eq!(summary.peak_network, None);
+        assert_eq!(summary.lines(UnitStyle::Binary, false).len(), 1);
+    }
+}
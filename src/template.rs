@@ -0,0 +1,234 @@
+use crate::i18n::{Text, fill, tr};
+use crate::monitor::{Sample, disk::DiskMonitor};
+
+// 可用占位符及其含义，百分比为 0-100，字节与速率均为原始数值
+const FIELDS: &[(&str, Field, Text)] = &[
+    ("cpu.total", Field::CpuTotal, Text::FieldCpuTotal),
+    ("cpu.avg", Field::CpuAverage, Text::FieldCpuAverage),
+    ("mem.used_pct", Field::MemUsedPct, Text::FieldMemUsedPct),
+    ("mem.used", Field::MemUsed, Text::FieldMemUsed),
+    ("mem.total", Field::MemTotal, Text::FieldMemTotal),
+    ("mem.available", Field::MemAvailable, Text::FieldMemAvailable),
+    ("swap.used_pct", Field::SwapUsedPct, Text::FieldSwapUsedPct),
+    ("swap.used", Field::SwapUsed, Text::FieldSwapUsed),
+    ("swap.total", Field::SwapTotal, Text::FieldSwapTotal),
+    ("disk.max_pct", Field::DiskMaxPct, Text::FieldDiskMaxPct),
+    ("net.rx", Field::NetRx, Text::FieldNetRx),
+    ("net.tx", Field::NetTx, Text::FieldNetTx),
+    ("gpu.util", Field::GpuUtil, Text::FieldGpuUtil),
+    ("gpu.mem_pct", Field::GpuMemPct, Text::FieldGpuMemPct),
+    ("gpu.temp", Field::GpuTemp, Text::FieldGpuTemp),
+    ("health", Field::Health, Text::FieldHealth),
+];
+
+/// 占位符表：名称、取值和 --help 中的说明
+pub(crate) type Fields = [(&'static str, Field, Text)];
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Field {
+    CpuTotal,
+    CpuAverage,
+    MemUsedPct,
+    MemUsed,
+    MemTotal,
+    MemAvailable,
+    SwapUsedPct,
+    SwapUsed,
+    SwapTotal,
+    DiskMaxPct,
+    NetRx,
+    NetTx,
+    GpuUtil,
+    GpuMemPct,
+    GpuTemp,
+    Health,
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Literal(String),
+    Field {
+        field: Field,
+        width: Option<usize>,
+        precision: Option<usize>,
+    },
+}
+
+/// 自定义输出模板，形如 `CPU {cpu.total:.0}% MEM {mem.used_pct:5.1}%`
+///
+/// 占位符可带 `:宽度.精度` 格式说明，未指定精度时保留 1 位小数；
+/// `{{` 和 `}}` 输出字面量花括号。数据不可用（如无 GPU）时输出 `N/A`。
+#[derive(Debug, Clone)]
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+impl Template {
+    pub fn parse(template: &str) -> Result<Self, String> {
+        Self::parse_fields(template, FIELDS)
+    }
+
+    /// 按给定的占位符表解析，供语法相同、占位符不同的格式（如 --oneline）复用
+    pub(crate) fn parse_fields(template: &str, fields: &Fields) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let mut placeholder = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => placeholder.push(c),
+                            None => return Err(fill(Text::TemplateUnclosed, &[&placeholder])),
+                        }
+                    }
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                    }
+                    segments.push(Self::parse_placeholder(&placeholder, fields)?);
+                }
+                '}' => return Err(tr(Text::TemplateStrayBrace).to_string()),
+                c => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+        Ok(Self { segments })
+    }
+
+    pub fn render(&self, sample: &Sample) -> String {
+        self.render_with(sample, |_, value, precision| format!("{:.*}", precision.unwrap_or(1), value))
+    }
+
+    /// 由 format 将各占位符的取值和精度转换为文本，再按宽度右对齐
+    pub(crate) fn render_with(&self, sample: &Sample, format: impl Fn(Field, f64, Option<usize>) -> String) -> String {
+        let mut output = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(text) => output.push_str(text),
+                Segment::Field { field, width, precision } => {
+                    let text = match Self::value(*field, sample) {
+                        Some(value) => format(*field, value, *precision),
+                        None => "N/A".to_string(),
+                    };
+                    output.push_str(&format!("{:>width$}", text, width = width.unwrap_or(0)));
+                }
+            }
+        }
+        output
+    }
+
+    fn parse_placeholder(placeholder: &str, fields: &Fields) -> Result<Segment, String> {
+        let (name, spec) = match placeholder.split_once(':') {
+            Some((name, spec)) => (name.trim(), Some(spec.trim())),
+            None => (placeholder.trim(), None),
+        };
+
+        let field = fields.iter()
+            .find(|(field_name, _, _)| *field_name == name)
+            .map(|(_, field, _)| *field)
+            .ok_or_else(|| {
+                let names: Vec<&str> = fields.iter().map(|(name, _, _)| *name).collect();
+                fill(Text::TemplateUnknown, &[name, &names.join(", ")])
+            })?;
+
+        let (width, precision) = match spec {
+            Some(spec) => Self::parse_spec(spec)?,
+            None => (None, None),
+        };
+        Ok(Segment::Field { field, width, precision })
+    }
+
+    // 格式说明：[宽度][.精度]
+    fn parse_spec(spec: &str) -> Result<(Option<usize>, Option<usize>), String> {
+        let invalid = || fill(Text::TemplateInvalidSpec, &[spec]);
+        let (width, precision) = match spec.split_once('.') {
+            Some((width, precision)) => (width, Some(precision)),
+            None => (spec, None),
+        };
+
+        let width = match width {
+            "" => None,
+            width => Some(width.parse().map_err(|_| invalid())?),
+        };
+        let precision = match precision {
+            Some(precision) => Some(precision.parse().map_err(|_| invalid())?),
+            None => None,
+        };
+        Ok((width, precision))
+    }
+
+    fn value(field: Field, sample: &Sample) -> Option<f64> {
+        let memory = sample.memory.as_ref();
+        let gpu = sample.gpu.as_ref();
+        match field {
+            Field::CpuTotal => sample.cpu.as_ref().map(|cpu| cpu.total_usage as f64),
+            Field::CpuAverage => sample.cpu.as_ref().map(|cpu| cpu.average_usage as f64),
+            Field::MemUsedPct => memory.map(|m| DiskMonitor::usage_percentage(m.total, m.used)),
+            Field::MemUsed => memory.map(|m| m.used as f64),
+            Field::MemTotal => memory.map(|m| m.total as f64),
+            Field::MemAvailable => memory.map(|m| m.available as f64),
+            Field::SwapUsedPct => memory.and_then(|m| m.swap_usage()),
+            Field::SwapUsed => memory.map(|m| m.swap_used as f64),
+            Field::SwapTotal => memory.map(|m| m.swap_total as f64),
+            Field::DiskMaxPct => sample.disks.iter()
+                .filter(|disk| disk.error.is_none())
+                .map(|disk| DiskMonitor::usage_percentage(disk.total_space, disk.used_space))
+                .reduce(f64::max),
+            Field::NetRx => Some(sample.networks.iter().map(|net| net.received_bytes).sum::<u64>() as f64),
+            Field::NetTx => Some(sample.networks.iter().map(|net| net.transmitted_bytes).sum::<u64>() as f64),
+            Field::GpuUtil => gpu.map(|g| g.utilization as f64),
+            Field::GpuMemPct => gpu.map(|g| DiskMonitor::usage_percentage(g.memory_total, g.memory_used)),
+            Field::GpuTemp => gpu.map(|g| g.temperature as f64),
+            Field::Health => sample.health.as_ref().map(|h| h.score),
+        }
+    }
+
+    // 用于 --help 的占位符说明
+    pub fn help() -> String {
+        Self::help_fields(FIELDS)
+    }
+
+    pub(crate) fn help_fields(fields: &Fields) -> String {
+        fields.iter()
+            .map(|(name, _, description)| format!("  {{{}}}  {}", name, tr(*description)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitor::memory::MemoryStats;
+
+    fn render(template: &str, sample: &Sample) -> String {
+        Template::parse(template).unwrap().render(sample)
+    }
+
+    #[test]
+    fn swap_disabled_renders_not_available() {
+        let sample = Sample { memory: Some(MemoryStats::with_usage(100, 50, 0, 0)), ..Sample::empty() };
+        assert_eq!(render("{swap.used_pct:.0}", &sample), "N/A");
+        assert_eq!(render("{swap.total:.0}", &sample), "0");
+    }
+
+    #[test]
+    fn swap_enabled_renders_percent() {
+        let sample = Sample { memory: Some(MemoryStats::with_usage(100, 50, 400, 100)), ..Sample::empty() };
+        assert_eq!(render("{swap.used_pct}%", &sample), "25.0%");
+    }
+}
@@ -0,0 +1,297 @@
+use std::time::{Duration, UNIX_EPOCH};
+use crate::cli::{TempUnit, UnitStyle};
+use crate::monitor::{
+    Sample,
+    disk::{DiskMonitor, DiskStats},
+    memory::MemoryMonitor,
+    network::{NetworkMonitor, NetworkStats},
+};
+use ratatui::layout::Alignment;
+use super::table::TextTable;
+use crate::i18n::{Text, fill, tr};
+use crate::util::{format_core_frequency, format_duration, format_frequency, format_temperature, format_timestamp};
+
+/// 平铺列表和文本输出的显示选项
+#[derive(Debug, Clone, Copy)]
+pub struct FlatOptions {
+    pub averaged: bool,
+    pub per_core: bool,     // 逐个列出核心的使用率和频率
+    pub net_verbose: bool,  // 每个网卡额外输出包数、错误数和地址
+    pub net_bits: bool,     // 网络速率以比特/秒显示
+    pub tables: bool,       // 磁盘和网卡以对齐的表格输出
+    pub units: UnitStyle,
+    pub temp_unit: TempUnit,
+}
+
+/// 将一次采样展开为纯文本行，供平铺模式（读屏软件、小终端）逐行浏览
+pub fn flat_lines(sample: &Sample, options: &FlatOptions) -> Vec<String> {
+    let FlatOptions { averaged, per_core, net_verbose, net_bits, tables, units, temp_unit } = *options;
+    let mut lines = vec![fill(
+        Text::Uptime,
+        &[
+            &format_duration(Duration::from_secs(sample.uptime)),
+            &format_timestamp(UNIX_EPOCH + Duration::from_secs(sample.boot_time)),
+        ],
+    )];
+
+    if let Some(health) = &sample.health {
+        let (bottleneck, pressure) = health.bottleneck();
+        lines.push(format!("{}: {:.0} {}", tr(Text::HealthScore), health.score, tr(Text::Points)));
+        lines.push(format!("{}: {} {:.0}%", tr(Text::Bottleneck), bottleneck, pressure));
+    }
+
+    lines.push(format!("{}: {}", tr(Text::CpuModel), sample.cpu_info.brand));
+    if let Some(cpu) = &sample.cpu {
+        let (total, cores) = if averaged {
+            (cpu.average_usage, &cpu.core_average)
+        } else {
+            (cpu.total_usage, &cpu.core_usage)
+        };
+        lines.push(format!(
+            "{}: {:.1}%{}",
+            tr(Text::CpuTotalUsage),
+            total,
+            cpu.temperature.map(|t| format!(" ({})", format_temperature(t, temp_unit))).unwrap_or_default()
+        ));
+        if let Some(load) = &sample.load_average {
+            let values = format!("{:.2}, {:.2}, {:.2}", load.one, load.five, load.fifteen);
+            lines.push(fill(Text::LoadAverage, &[&values]));
+        }
+        lines.push(format!(
+            "{}: {}",
+            tr(Text::CpuAverageFrequency),
+            format_frequency(cpu.average_frequency())
+        ));
+        for (i, usage) in cores.iter().enumerate().filter(|_| per_core) {
+            let freq = cpu.frequency.get(i).copied().unwrap_or(0);
+            let max_freq = cpu.max_frequency.get(i).copied().unwrap_or(0);
+            lines.push(fill(
+                Text::CoreLine,
+                &[&i.to_string(), &format!("{:.1}%", usage), &format_core_frequency(freq, max_freq)],
+            ));
+        }
+    }
+
+    if let Some(mem) = &sample.memory {
+        lines.push(format!(
+            "{}: {} / {} ({:.1}%)",
+            tr(Text::MemoryUsed),
+            MemoryMonitor::format_bytes(mem.used, units),
+            MemoryMonitor::format_bytes(mem.total, units),
+            DiskMonitor::usage_percentage(mem.total, mem.used)
+        ));
+        lines.push(format!("{}: {}", tr(Text::MemoryAvailable), MemoryMonitor::format_bytes(mem.available, units)));
+        match mem.swap_usage() {
+            Some(usage) => lines.push(format!(
+                "{}: {} / {} ({:.1}%)",
+                tr(Text::SwapUsed),
+                MemoryMonitor::format_bytes(mem.swap_used, units),
+                MemoryMonitor::format_bytes(mem.swap_total, units),
+                usage
+            )),
+            None => lines.push(format!("{}: {}", tr(Text::Swap), tr(Text::Disabled))),
+        }
+    }
+
+    if tables && !sample.disks.is_empty() {
+        lines.extend(disk_table(&sample.disks, units));
+    }
+    for disk in sample.disks.iter().filter(|_| !tables) {
+        match &disk.error {
+            Some(error) => lines.push(fill(Text::DiskReadFailed, &[&disk.mount_point, error])),
+            None => lines.push(fill(Text::DiskLine, &[
+                &disk.mount_point,
+                &match disk.file_system.as_str() {
+                    "" => String::new(),
+                    file_system => format!(" ({})", file_system),
+                },
+                &format!(
+                    "{} / {} ({:.1}%)",
+                    MemoryMonitor::format_bytes(disk.used_space, units),
+                    MemoryMonitor::format_bytes(disk.total_space, units),
+                    DiskMonitor::usage_percentage(disk.total_space, disk.used_space)
+                ),
+                &if disk.is_read_only() { format!(", {}", tr(Text::ReadOnly)) } else { String::new() },
+                &NetworkMonitor::format_speed(disk.read_bytes_per_sec as f64, units),
+                &NetworkMonitor::format_speed(disk.write_bytes_per_sec as f64, units),
+            ])),
+        }
+        if let Some(inodes) = &disk.inodes {
+            let usage = format!(
+                "{} / {} ({:.1}%)",
+                inodes.used,
+                inodes.total,
+                DiskMonitor::usage_percentage(inodes.total, inodes.used)
+            );
+            lines.push(fill(Text::DiskInodes, &[&disk.mount_point, &usage]));
+        }
+    }
+
+    if let Some(tcp) = &sample.tcp {
+        lines.push(format!(
+            "{}: {} {}, {} {}, {} {}, TIME_WAIT {}, CLOSE_WAIT {}",
+            tr(Text::TcpConnections),
+            tr(Text::Established),
+            tcp.established,
+            tr(Text::Listen),
+            tcp.listen,
+            tr(Text::Handshake),
+            tcp.syn,
+            tcp.time_wait,
+            tcp.close_wait
+        ));
+    }
+    let total = NetworkMonitor::aggregate(&sample.networks);
+    let networks = sample.networks.iter()
+        .flat_map(|net| std::iter::once(net).chain(net.children.iter()));
+    if tables {
+        if !sample.networks.is_empty() {
+            lines.extend(network_table(networks, &total, options));
+        }
+    } else if !sample.networks.is_empty() {
+        // 合计行没有地址，不输出地址行
+        let rows = networks.map(|net| (net, true)).chain(std::iter::once((&total, false)));
+        for (net, has_addresses) in rows {
+            let (rx, tx) = rates(net, averaged);
+            lines.push(format!("{} {}: {}", net.interface_name, tr(Text::Receive), NetworkMonitor::format_net_speed(rx as f64, units, net_bits)));
+            lines.push(format!("{} {}: {}", net.interface_name, tr(Text::Transmit), NetworkMonitor::format_net_speed(tx as f64, units, net_bits)));
+            if net_verbose {
+                lines.push(format!("{} {}", net.interface_name, NetworkMonitor::packet_summary(net)));
+                if has_addresses && NetworkMonitor::ADDRESSES_SUPPORTED {
+                    lines.push(format!("{} {}", net.interface_name, NetworkMonitor::address_summary(net)));
+                }
+            }
+        }
+    }
+
+    if let Some(gpu) = &sample.gpu {
+        lines.push(format!("{}: {}", tr(Text::GpuModel), gpu.name));
+        lines.push(format!("{}: {}%", tr(Text::GpuUsage), gpu.utilization));
+        lines.push(format!("{}: {}", tr(Text::GpuTemperature), format_temperature(gpu.temperature as f32, temp_unit)));
+        if let Some(fan) = gpu.fan_speed_percent {
+            lines.push(format!("{}: {}%", tr(Text::GpuFan), fan));
+        }
+        if let Some(power) = gpu.power_usage_watts {
+            lines.push(format!("{}: {} W", tr(Text::GpuPower), power));
+        }
+        lines.push(format!(
+            "{}: {} / {} ({:.1}%)",
+            tr(Text::VramUsed),
+            MemoryMonitor::format_bytes(gpu.memory_used, units),
+            MemoryMonitor::format_bytes(gpu.memory_total, units),
+            DiskMonitor::usage_percentage(gpu.memory_total, gpu.memory_used)
+        ));
+        if !gpu.throttle_reasons.is_empty() {
+            lines.push(format!("{}: {}", tr(Text::GpuThrottle), gpu.throttle_text()));
+        }
+    }
+
+    if let Some(entropy) = &sample.entropy {
+        lines.push(format!("{}: {} / {} bits", tr(Text::KernelEntropy), entropy.available, entropy.pool_size));
+    }
+
+    for array in sample.raid.iter().flatten() {
+        let mut line = fill(Text::RaidLine, &[
+            &array.name,
+            &array.level,
+            &array.state,
+            &format!("{}/{}", array.active_disks, array.total_disks),
+        ]);
+        if array.is_degraded() {
+            line.push_str(&format!(", {}", tr(Text::Degraded)));
+        }
+        lines.push(line);
+    }
+
+    lines
+}
+
+fn rates(net: &NetworkStats, averaged: bool) -> (u64, u64) {
+    if averaged {
+        (net.average_received_bytes, net.average_transmitted_bytes)
+    } else {
+        (net.received_bytes, net.transmitted_bytes)
+    }
+}
+
+// 读取失败的磁盘容量各列留空，使用率列显示原因
+fn disk_table(disks: &[DiskStats], units: UnitStyle) -> Vec<String> {
+    let mut table = TextTable::new(&[
+        (tr(Text::Device), Alignment::Left),
+        (tr(Text::MountPoint), Alignment::Left),
+        (tr(Text::Type), Alignment::Left),
+        (tr(Text::Used), Alignment::Right),
+        (tr(Text::Total), Alignment::Right),
+        (tr(Text::Usage), Alignment::Right),
+    ]);
+    for disk in disks {
+        let (used, total, usage) = match &disk.error {
+            Some(error) => (String::new(), String::new(), format!("{}: {}", tr(Text::ReadFailed), error)),
+            None => (
+                MemoryMonitor::format_bytes(disk.used_space, units),
+                MemoryMonitor::format_bytes(disk.total_space, units),
+                format!("{:.1}%", DiskMonitor::usage_percentage(disk.total_space, disk.used_space)),
+            ),
+        };
+        table.push(vec![
+            disk.name.clone(),
+            disk.mount_point.clone(),
+            disk.file_system.clone(),
+            used,
+            total,
+            usage,
+        ]);
+    }
+    table.render()
+}
+
+fn network_table<'a>(
+    networks: impl Iterator<Item = &'a NetworkStats>,
+    total: &NetworkStats,
+    options: &FlatOptions,
+) -> Vec<String> {
+    let FlatOptions { averaged, net_verbose, net_bits, units, .. } = *options;
+    let mut columns = vec![
+        (tr(Text::Interface), Alignment::Left),
+        (tr(Text::Receive), Alignment::Right),
+        (tr(Text::Transmit), Alignment::Right),
+        (tr(Text::TotalReceived), Alignment::Right),
+        (tr(Text::TotalTransmitted), Alignment::Right),
+    ];
+    if net_verbose {
+        columns.extend([
+            (tr(Text::PacketsReceived), Alignment::Right),
+            (tr(Text::PacketsTransmitted), Alignment::Right),
+            (tr(Text::ErrorsReceived), Alignment::Right),
+            (tr(Text::ErrorsTransmitted), Alignment::Right),
+        ]);
+        if NetworkMonitor::ADDRESSES_SUPPORTED {
+            columns.push((tr(Text::Addresses), Alignment::Left));
+        }
+    }
+    let mut table = TextTable::new(&columns);
+    let rows = networks.map(|net| (net, true)).chain(std::iter::once((total, false)));
+    for (net, has_addresses) in rows {
+        let (rx, tx) = rates(net, averaged);
+        let mut row = vec![
+            net.interface_name.clone(),
+            NetworkMonitor::format_net_speed(rx as f64, units, net_bits),
+            NetworkMonitor::format_net_speed(tx as f64, units, net_bits),
+            MemoryMonitor::format_bytes(net.total_received, units),
+            MemoryMonitor::format_bytes(net.total_transmitted, units),
+        ];
+        if net_verbose {
+            row.extend([
+                net.packets_received,
+                net.packets_transmitted,
+                net.errors_on_received,
+                net.errors_on_transmitted,
+            ].map(|count| count.to_string()));
+            if NetworkMonitor::ADDRESSES_SUPPORTED {
+                row.push(if has_addresses { NetworkMonitor::address_summary(net) } else { String::new() });
+            }
+        }
+        table.push(row);
+    }
+    table.render()
+}
@@ -0,0 +1,58 @@
+use std::collections::VecDeque;
+use std::time::SystemTime;
+
+/// 固定容量的环形缓冲区，保存最近的采样值及其采样时刻，用于绘制走势图
+pub struct History {
+    values: VecDeque<(SystemTime, u64)>,
+    capacity: usize,
+}
+
+impl History {
+    // 不按容量预先分配，缓冲区随读数增长，很大的容量不会在启动时占用内存
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            values: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    pub fn push(&mut self, timestamp: SystemTime, value: u64) {
+        if self.values.len() == self.capacity {
+            self.values.pop_front();
+        }
+        self.values.push_back((timestamp, value));
+    }
+
+    // 最近的 n 个值，按时间从旧到新排列
+    pub fn latest(&self, n: usize) -> Vec<u64> {
+        let skip = self.values.len().saturating_sub(n);
+        self.values.iter().skip(skip).map(|&(_, value)| value).collect()
+    }
+
+    // 全部读数及采样时刻，按时间从旧到新排列
+    pub fn entries(&self) -> Vec<(SystemTime, u64)> {
+        self.values.iter().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_latest_values_up_to_capacity() {
+        let mut history = History::new(3);
+        for value in 1..=5 {
+            history.push(SystemTime::UNIX_EPOCH, value);
+        }
+        assert_eq!(history.latest(10), [3, 4, 5]);
+        assert_eq!(history.latest(2), [4, 5]);
+        assert_eq!(history.entries().len(), 3);
+    }
+
+    #[test]
+    fn large_capacity_does_not_preallocate() {
+        let history = History::new(usize::MAX);
+        assert!(history.latest(1).is_empty());
+    }
+}
@@ -1,30 +1,93 @@
-use std::io;
+mod flat;
+mod history;
+mod panel;
+mod table;
+mod theme;
+
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Write};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture, KeyEvent, KeyCode},
+    event::{DisableMouseCapture, EnableMouseCapture, KeyEvent},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
     backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout},
-    style::{Color, Style},
-    widgets::{Block, Borders, Gauge, List, ListItem, Paragraph},
-    Terminal,
+    buffer::{Buffer, Cell},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Gauge, List, ListItem, Paragraph, Row, Sparkline, Table, Tabs},
+    Frame, Terminal,
 };
 
 use crate::{
+    cli::{DiskDisplay, UnitStyle},
     monitor::{
-        Monitor,
-        disk::DiskMonitor,
+        Sample,
+        Subsystem,
+        AVERAGE_WINDOW,
+        cpu::CpuInfo,
+        disk::{DiskMonitor, DiskStats},
         memory::MemoryMonitor,
-        network::NetworkMonitor,
+        network::{NetworkMonitor, NetworkStats},
+        process::{ProcessMonitor, ProcessSort},
     },
     error::Result,
+    i18n::{Text, fill, tr},
+    recorder::Recorder,
+    util::{format_core_frequency, format_duration, format_frequency, format_temperature},
 };
+use self::history::History;
+use self::panel::{Panel, Scroll};
+pub use self::flat::{FlatOptions, flat_lines};
+pub use self::theme::{Levels, Theme};
+
+// GPU 温度走势保留的采样数
+const GPU_TEMP_HISTORY: usize = 300;
+// CPU 和内存走势默认保留的采样数（--history）
+const TREND_HISTORY: usize = 120;
+// 获得焦点的面板的边框颜色
+const FOCUS_BORDER: Color = Color::LightMagenta;
+// 指定 --net-max 时接口带宽占用的告警分界
+const NET_LEVELS: Levels = Levels::new(70.0, 90.0);
 
 pub struct Tui {
     terminal: Terminal<CrosstermBackend<io::Stdout>>,
-    cpu_scroll: usize,
+    focus: Panel,
+    panels: Vec<Panel>,             // 上次绘制时显示的面板，Tab 只在其中切换
+    scroll: HashMap<Panel, Scroll>, // 各面板列表的滚动位置
+    cores_per_page: Option<usize>,  // 用户固定的每页核心数
+    disk_display: DiskDisplay,
+    theme: Theme,
+    units: UnitStyle,
+    status: Option<String>,  // 底部状态栏消息
+    alert: Option<String>,   // 处于触发状态的告警，常驻状态栏直到解除
+    cpu_details: bool,       // 是否展开 CPU 详情
+    swap_details: bool,      // 是否展开交换设备明细
+    net_children: bool,      // 是否展开汇总接口的子接口
+    disk_selected: usize,    // 磁盘面板中选中的磁盘
+    disk_details: bool,      // 以详情视图显示选中的磁盘
+    process_sort: ProcessSort,
+    averaged: bool,          // 显示平均值而非瞬时值
+    paused: bool,            // 冻结画面，停止采样
+    flash: Option<(String, Instant)>,  // 短暂显示的提示消息
+    snapshot_requested: bool,
+    tabs: Option<(Vec<String>, usize)>,  // 多主机面板的标签页标题和当前页
+    cpu_history: History,
+    memory_history: History,  // 内存已用百分比
+    history: usize,           // CPU 和内存走势保留的采样数，超过图宽的部分只用于导出
+    gpu_temp_history: History,
+    flat: bool,              // 平铺文本模式，无仪表和颜色
+    net_verbose: bool,       // 平铺模式中显示网卡包数和错误数
+    net_bits: bool,          // 网络速率以比特/秒显示
+    color: bool,             // 为 false 时（--no-color、NO_COLOR）不输出颜色
+    net_max: Option<u64>,    // 接口带宽条的满量程（字节/秒），未指定时按各接口观测到的峰值
+    net_peaks: HashMap<String, u64>,  // 各接口观测到的最高速率（字节/秒）
+    flat_scroll: Scroll,
+    last_trend_sample: Option<SystemTime>,  // 已记入走势历史的采样时刻，重绘时不重复记录
+    last_gpu_sample: Option<SystemTime>,
 }
 
 impl Tui {
@@ -33,23 +96,197 @@ impl Tui {
         let terminal = Terminal::new(backend)?;
         Ok(Self { 
             terminal,
-            cpu_scroll: 0,
+            focus: Panel::Cpu,
+            panels: Vec::new(),
+            scroll: HashMap::new(),
+            cores_per_page: None,
+            disk_display: DiskDisplay::Used,
+            theme: Theme::default(),
+            units: UnitStyle::Binary,
+            status: None,
+            alert: None,
+            cpu_details: false,
+            swap_details: false,
+            net_children: false,
+            disk_selected: 0,
+            disk_details: false,
+            process_sort: ProcessSort::Cpu,
+            averaged: false,
+            paused: false,
+            flash: None,
+            snapshot_requested: false,
+            tabs: None,
+            cpu_history: History::new(TREND_HISTORY),
+            memory_history: History::new(TREND_HISTORY),
+            history: TREND_HISTORY,
+            gpu_temp_history: History::new(GPU_TEMP_HISTORY),
+            flat: false,
+            net_verbose: false,
+            net_bits: false,
+            color: true,
+            net_max: None,
+            net_peaks: HashMap::new(),
+            flat_scroll: Scroll::default(),
+            last_trend_sample: None,
+            last_gpu_sample: None,
         })
     }
 
-    pub fn handle_scroll(&mut self, key: KeyEvent, max_cores: usize) {
-        match key.code {
-            KeyCode::Up => {
-                if self.cpu_scroll > 0 {
-                    self.cpu_scroll -= 1;
-                }
-            }
-            KeyCode::Down => {
-                if self.cpu_scroll < max_cores.saturating_sub(10) {
-                    self.cpu_scroll += 1;
-                }
-            }
-            _ => {}
+    pub fn with_cores_per_page(mut self, cores_per_page: Option<usize>) -> Self {
+        self.cores_per_page = cores_per_page.map(|n| n.max(1));
+        self
+    }
+
+    pub fn with_disk_display(mut self, disk_display: DiskDisplay) -> Self {
+        self.disk_display = disk_display;
+        self
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn with_units(mut self, units: UnitStyle) -> Self {
+        self.units = units;
+        self
+    }
+
+    pub fn with_flat(mut self, flat: bool) -> Self {
+        self.flat = flat;
+        self
+    }
+
+    pub fn with_net_verbose(mut self, net_verbose: bool) -> Self {
+        self.net_verbose = net_verbose;
+        self
+    }
+
+    pub fn with_net_bits(mut self, net_bits: bool) -> Self {
+        self.net_bits = net_bits;
+        self
+    }
+
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_history(mut self, history: usize) -> Self {
+        self.history = history;
+        self.cpu_history = History::new(history);
+        self.memory_history = History::new(history);
+        self
+    }
+
+    pub fn with_net_max(mut self, net_max: Option<u64>) -> Self {
+        self.net_max = net_max.filter(|max| *max > 0);
+        self
+    }
+
+    // 切换到其他主机时清空走势历史，避免不同主机的数据混在一起
+    pub fn set_tabs(&mut self, titles: Vec<String>, active: usize) {
+        if self.tabs.as_ref().is_some_and(|(_, previous)| *previous != active) {
+            self.cpu_history = History::new(self.history);
+            self.memory_history = History::new(self.history);
+            self.gpu_temp_history = History::new(GPU_TEMP_HISTORY);
+            self.net_peaks.clear();
+            self.last_trend_sample = None;
+            self.last_gpu_sample = None;
+        }
+        self.tabs = Some((titles, active));
+    }
+
+    pub fn set_status(&mut self, status: Option<String>) {
+        self.status = status;
+    }
+
+    pub fn set_alert(&mut self, alert: Option<String>) {
+        self.alert = alert;
+    }
+
+    pub fn toggle_cpu_details(&mut self) {
+        self.cpu_details = !self.cpu_details;
+    }
+
+    pub fn toggle_swap_details(&mut self) {
+        self.swap_details = !self.swap_details;
+    }
+
+    pub fn toggle_net_children(&mut self) {
+        self.net_children = !self.net_children;
+    }
+
+    // 磁盘面板获得焦点时查看选中磁盘的详情，Esc 返回
+    pub fn open_disk_details(&mut self) {
+        if self.focus == Panel::Disk && !self.flat {
+            self.disk_details = true;
+        }
+    }
+
+    pub fn close_disk_details(&mut self) {
+        self.disk_details = false;
+    }
+
+    pub fn cycle_process_sort(&mut self) {
+        self.process_sort = self.process_sort.next();
+    }
+
+    pub fn toggle_averaged(&mut self) {
+        self.averaged = !self.averaged;
+    }
+
+    pub fn toggle_paused(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    // 在下一次绘制后把当前画面保存为 ANSI 文本文件
+    pub fn request_snapshot(&mut self) {
+        self.snapshot_requested = true;
+    }
+
+    /// 将 CPU 和内存走势的历史读数导出为当前目录下的 CSV 文件，并短暂提示保存路径
+    pub fn export_history(&mut self) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let path = format!("litemon-history-{}.csv", timestamp);
+        let result = Recorder::write_history(&path, &self.cpu_history.entries(), &self.memory_history.entries());
+        let message = match result {
+            Ok(()) => format!("{}: {}", tr(Text::HistorySaved), path),
+            Err(e) => format!("{}: {}", tr(Text::HistoryFailed), e),
+        };
+        self.flash = Some((message, Instant::now()));
+    }
+
+    // 在显示中的面板间循环切换焦点，backward 为反向
+    pub fn cycle_focus(&mut self, backward: bool) {
+        if self.panels.is_empty() {
+            return;
+        }
+        let len = self.panels.len();
+        let next = match self.panels.iter().position(|panel| *panel == self.focus) {
+            Some(i) if backward => (i + len - 1) % len,
+            Some(i) => (i + 1) % len,
+            None => 0,
+        };
+        self.focus = self.panels[next];
+    }
+
+    // 平铺模式滚动整张列表，否则滚动获得焦点的面板；磁盘面板移动选中行
+    pub fn handle_scroll(&mut self, key: KeyEvent) {
+        if self.flat {
+            self.flat_scroll.handle_key(key);
+        } else if self.focus == Panel::Disk {
+            let scroll = self.scroll.entry(Panel::Disk).or_default();
+            self.disk_selected = scroll.select(self.disk_selected, key);
+        } else {
+            self.scroll.entry(self.focus).or_default().handle_key(key);
         }
     }
 
@@ -64,9 +301,117 @@ impl Tui {
         Ok(())
     }
 
-    pub fn draw(&mut self, monitor: &mut Monitor) -> Result<()> {
-        self.terminal.draw(|frame| {
-            let size = frame.size();
+    pub fn draw(&mut self, sample: &Sample) -> Result<()> {
+        // CPU 和内存走势按同一节奏记录：每个新采样一次，始终记录瞬时值
+        if self.last_trend_sample != Some(sample.timestamp) {
+            if let Some(cpu_stats) = &sample.cpu {
+                self.cpu_history.push(sample.timestamp, cpu_stats.total_usage.round() as u64);
+            }
+            if let Some(mem_stats) = &sample.memory {
+                let usage = DiskMonitor::usage_percentage(mem_stats.total, mem_stats.used);
+                self.memory_history.push(sample.timestamp, usage.round() as u64);
+            }
+            let total = NetworkMonitor::aggregate(&sample.networks);
+            let networks = sample.networks.iter()
+                .flat_map(|net| std::iter::once(net).chain(&net.children))
+                .chain(std::iter::once(&total));
+            let mut present = HashSet::new();
+            for net in networks {
+                let peak = self.net_peaks.entry(net.interface_name.clone()).or_default();
+                *peak = (*peak).max(net.received_bytes).max(net.transmitted_bytes);
+                present.insert(net.interface_name.as_str());
+            }
+            // 已消失的接口不再保留峰值
+            self.net_peaks.retain(|name, _| present.contains(name.as_str()));
+            self.last_trend_sample = Some(sample.timestamp);
+        }
+
+        self.panels = [
+            (Panel::Cpu, sample.cpu.is_some()),
+            (Panel::Memory, sample.memory.is_some()),
+            (Panel::Disk, !sample.disks.is_empty()),
+            (Panel::Network, !sample.networks.is_empty()),
+            (Panel::Connections, sample.connections.is_some()),
+            (Panel::Process, !sample.processes.is_empty()),
+            (Panel::Gpu, sample.gpu.is_some()),
+        ].into_iter()
+            .filter_map(|(panel, shown)| shown.then_some(panel))
+            .collect();
+        if !self.panels.contains(&self.focus) {
+            if let Some(first) = self.panels.first() {
+                self.focus = *first;
+            }
+        }
+
+        let completed = self.terminal.draw(|frame| {
+            let mut size = frame.size();
+
+            if let Some(tabs) = &self.tabs {
+                size = Self::render_tabs(frame, size, tabs);
+            }
+
+            // 有消息时在底部留出一行状态栏
+            let mut status_parts = Vec::new();
+            if self.paused {
+                status_parts.push(tr(Text::Paused).to_string());
+            }
+            if self.averaged {
+                status_parts.push(fill(Text::AveragedStatus, &[&AVERAGE_WINDOW.to_string()]));
+            }
+            if let Some(status) = &self.status {
+                status_parts.push(status.clone());
+            }
+            if let Some(alert) = &self.alert {
+                status_parts.push(alert.clone());
+            }
+            if let Some((message, since)) = &self.flash {
+                if since.elapsed() < Duration::from_secs(3) {
+                    status_parts.push(message.clone());
+                }
+            }
+            if !status_parts.is_empty() {
+                let rows = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(0), Constraint::Length(1)].as_ref())
+                    .split(size);
+                let status_line = Paragraph::new(status_parts.join(" "))
+                    .style(Style::default().fg(Color::Yellow));
+                frame.render_widget(status_line, rows[1]);
+                size = rows[0];
+            }
+
+            if self.flat {
+                let lines = flat::flat_lines(sample, &FlatOptions {
+                    averaged: self.averaged,
+                    per_core: true,
+                    net_verbose: self.net_verbose,
+                    net_bits: self.net_bits,
+                    tables: false,
+                    units: self.units,
+                    temp_unit: self.theme.temp_unit,
+                });
+                let len = lines.len();
+                let offset = self.flat_scroll.update(size.height.saturating_sub(2) as usize, len);
+                let items: Vec<ListItem> = lines.into_iter()
+                    .skip(offset)
+                    .map(ListItem::new)
+                    .collect();
+                let list = List::new(items)
+                    .block(Block::default()
+                        .title(format!("{} ({}/{})", tr(Text::SystemResources), offset, len))
+                        .borders(Borders::ALL));
+                frame.render_widget(list, size);
+                if !self.color {
+                    Self::strip_colors(frame.buffer_mut());
+                }
+                return;
+            }
+
+            let border = |panel: Panel| if self.focus == panel {
+                Style::default().fg(FOCUS_BORDER)
+            } else {
+                Style::default()
+            };
 
             // 将界面分为左右栏
             let main_chunks = Layout::default()
@@ -81,242 +426,874 @@ impl Tui {
             let left_chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([
-                    Constraint::Length(3),  // CPU型号
+                    Constraint::Length(if self.cpu_details { 6 } else { 3 }),  // CPU型号
                     Constraint::Length(3),  // CPU使用率
+                    Constraint::Length(if sample.cpu.is_some() { 4 } else { 0 }),  // CPU 走势
                     Constraint::Min(0),     // CPU核表
-                    Constraint::Length(10), // GPU 信息
+                    Constraint::Length(match (&sample.gpu, sample.unavailable.contains_key(&Subsystem::Gpu)) {
+                        (Some(_), _) => 16,
+                        (None, true) => 3,  // GPU 不可用的提示
+                        (None, false) => 0,
+                    }), // GPU 信息
                 ].as_ref())
                 .split(main_chunks[0]);
 
             // 右侧信息布局
+            let swap_device_count = match &sample.memory {
+                Some(mem_stats) if self.swap_details => mem_stats.swap_devices.len(),
+                _ => 0,
+            };
+            let swap_details_height = if swap_device_count > 0 { swap_device_count as u16 + 2 } else { 0 };
+            let mut info_constraints = vec![
+                Constraint::Length(3),   // 健康分
+                Constraint::Length(10 + swap_details_height),  // 内存、内存走势、交换分区（未启用时让给内存）和交换设备明细
+                Constraint::Length(8),   // 磁盘信息
+                Constraint::Length(12),  // 网络信息
+            ];
+            if sample.entropy.is_some() {
+                info_constraints.push(Constraint::Length(3));  // 内核熵池
+            }
+            if let Some(arrays) = &sample.raid {
+                info_constraints.push(Constraint::Length(arrays.len().max(1) as u16 + 2));  // RAID 阵列
+            }
+            if let Some(connections) = &sample.connections {
+                info_constraints.push(Constraint::Length(connections.len().clamp(1, 8) as u16 + 3));  // TCP 连接
+            }
+            info_constraints.push(Constraint::Min(0));  // 进程表
             let info_chunks = Layout::default()
                 .direction(Direction::Vertical)
-                .constraints([
-                    Constraint::Length(8),   // 内存和交换分区
-                    Constraint::Length(8),   // 磁盘信息
-                    Constraint::Length(12),  // 网络信息
-                ].as_ref())
+                .constraints(info_constraints)
                 .split(main_chunks[1]);
 
             // CPU 信息渲染
-            if let Ok(cpu_stats) = monitor.cpu_stats() {
+            if let Some(cpu_stats) = &sample.cpu {
                 // CPU型号信息
-                let cpu_info = Paragraph::new(monitor.cpu_info())
-                    .block(Block::default().title("CPU信息").borders(Borders::ALL))
+                let cpu_info = Paragraph::new(Self::cpu_info_lines(&sample.cpu_info, self.cpu_details, self.units))
+                    .block(Block::default()
+                        .title(format!(
+                            "{} │ {} {}",
+                            tr(Text::CpuInfo),
+                            tr(Text::UptimeShort),
+                            format_duration(Duration::from_secs(sample.uptime))
+                        ))
+                        .borders(Borders::ALL))
                     .style(Style::default().fg(Color::Cyan));
                 frame.render_widget(cpu_info, left_chunks[0]);
 
                 // 总体 CPU 使用率
-                let avg_freq = cpu_stats.frequency.iter().sum::<u64>() as f64 / cpu_stats.frequency.len() as f64;
+                let (total_usage, core_usage) = if self.averaged {
+                    (cpu_stats.average_usage, &cpu_stats.core_average)
+                } else {
+                    (cpu_stats.total_usage, &cpu_stats.core_usage)
+                };
                 let gauge = Gauge::default()
-                    .block(Block::default().title("总体CPU使用率").borders(Borders::ALL))
-                    .gauge_style(Style::default().fg(if cpu_stats.total_usage > 80.0 {
-                        Color::Red
-                    } else if cpu_stats.total_usage > 50.0 {
-                        Color::Yellow
-                    } else {
-                        Color::Cyan
-                    }))
+                    .block(Block::default().title(match &sample.load_average {
+                        Some(load) => format!(
+                            "{} │ {} {:.2} {:.2} {:.2}",
+                            tr(Text::OverallCpuUsage), tr(Text::Load), load.one, load.five, load.fifteen
+                        ),
+                        None => tr(Text::OverallCpuUsage).to_string(),
+                    }).borders(Borders::ALL))
+                    .gauge_style(Style::default().fg(self.theme.color(total_usage as f64, self.theme.cpu)))
                     .label(format!(
-                        "{}% │ {:.1} GHz",
-                        cpu_stats.total_usage as u16,
-                        avg_freq / 1000.0
+                        "{}% │ {}{}",
+                        total_usage as u16,
+                        format_frequency(cpu_stats.average_frequency()),
+                        cpu_stats.temperature.map(|t| format!(" ({})", format_temperature(t, self.theme.temp_unit))).unwrap_or_default()
                     ))
-                    .percent(cpu_stats.total_usage as u16);
+                    .percent(Self::gauge_percent(total_usage as f64));
                 frame.render_widget(gauge, left_chunks[1]);
 
+                // 总体使用率走势，只取能放下的最近采样
+                let width = left_chunks[2].width.saturating_sub(2) as usize;
+                let usage_history = self.cpu_history.latest(width);
+                // 标题附带 top 风格的时间占比：us 用户、sy 系统、ni nice、id 空闲、wa iowait、hi 中断
+                let trend_title = match &cpu_stats.breakdown {
+                    Some(b) => format!(
+                        "{} │ us {:.1} sy {:.1} ni {:.1} id {:.1} wa {:.1} hi {:.1}",
+                        tr(Text::CpuTrend), b.user, b.system, b.nice, b.idle, b.iowait, b.irq
+                    ),
+                    None => tr(Text::CpuTrend).to_string(),
+                };
+                let sparkline = Sparkline::default()
+                    .block(Block::default().title(trend_title).borders(Borders::ALL))
+                    .data(&usage_history)
+                    .max(100)
+                    .style(Style::default().fg(Color::Cyan));
+                frame.render_widget(sparkline, left_chunks[2]);
+
                 // CPU 核心列表
                 let core_count = cpu_stats.core_usage.len();
-                let cores_per_page = ((left_chunks[2].height as usize - 2) / 2) * 2; // 确保是偶数
+                let cores_per_page = self.cores_per_page.unwrap_or(
+                    ((left_chunks[3].height as usize).saturating_sub(2) / 2) * 2  // 确保是偶数
+                );
+                let offset = self.scroll.entry(Panel::Cpu).or_default().update(cores_per_page, core_count);
 
-                let items: Vec<ListItem<'_>> = cpu_stats.core_usage.iter()
-                    .zip(cpu_stats.frequency.iter())
+                let items: Vec<ListItem<'_>> = core_usage.iter()
                     .enumerate()
-                    .skip(self.cpu_scroll)
+                    .skip(offset)
                     .take(cores_per_page)
-                    .map(|(i, (usage, freq))| Self::create_core_list_item(i, *usage, *freq))
+                    .map(|(i, usage)| {
+                        let freq = cpu_stats.frequency.get(i).copied().unwrap_or(0);
+                        let max_freq = cpu_stats.max_frequency.get(i).copied().unwrap_or(0);
+                        Self::create_core_list_item(&self.theme, i, *usage, format_core_frequency(freq, max_freq))
+                    })
                     .collect();
 
                 let scroll_indicator = format!(
-                    "CPU核心状态 ({}-{}/{})",
-                    self.cpu_scroll,
-                    (self.cpu_scroll + cores_per_page).min(core_count),
+                    "{} ({}-{}/{})",
+                    tr(Text::CpuCores),
+                    offset,
+                    (offset + cores_per_page).min(core_count),
                     core_count
                 );
 
                 let cores_list = List::new(items)
-                    .block(Block::default().title(scroll_indicator).borders(Borders::ALL))
+                    .block(Block::default().title(scroll_indicator).borders(Borders::ALL).border_style(border(Panel::Cpu)))
                     .style(Style::default().fg(Color::Cyan));
 
-                frame.render_widget(cores_list, left_chunks[2]);
+                frame.render_widget(cores_list, left_chunks[3]);
             }
 
             // GPU 信息显示
-            if let Ok(gpu_stats) = monitor.gpu_stats() {
+            if let Some(gpu_stats) = &sample.gpu {
+                if self.last_gpu_sample != Some(gpu_stats.timestamp) {
+                    self.gpu_temp_history.push(gpu_stats.timestamp, gpu_stats.temperature as u64);
+                    self.last_gpu_sample = Some(gpu_stats.timestamp);
+                }
+
                 let gpu_chunks = Layout::default()
                     .direction(Direction::Vertical)
                     .constraints([
                         Constraint::Length(3),  // GPU型号
                         Constraint::Length(3),  // GPU使用率
                         Constraint::Length(3),  // 显存使用率
+                        Constraint::Length(4),  // 温度走势
+                        Constraint::Length(3),  // 降频原因
                     ].as_ref())
-                    .split(left_chunks[3]);
+                    .split(left_chunks[4]);
 
                 // GPU型号
-                let gpu_info = Paragraph::new(gpu_stats.name)
-                    .block(Block::default().title("GPU信息").borders(Borders::ALL))
+                // 不支持的项不显示，避免被动散热的卡显示 0% 风扇转速
+                let mut gpu_text = gpu_stats.name.clone();
+                if let Some(fan) = gpu_stats.fan_speed_percent {
+                    gpu_text.push_str(&format!(" │ {} {}%", tr(Text::Fan), fan));
+                }
+                if let Some(power) = gpu_stats.power_usage_watts {
+                    gpu_text.push_str(&format!(" │ {} {} W", tr(Text::Power), power));
+                }
+                let gpu_info = Paragraph::new(gpu_text)
+                    .block(Block::default().title(tr(Text::GpuInfo)).borders(Borders::ALL).border_style(border(Panel::Gpu)))
                     .style(Style::default().fg(Color::Green));
                 frame.render_widget(gpu_info, gpu_chunks[0]);
 
                 // GPU使用率
                 let gpu_usage = Gauge::default()
-                    .block(Block::default().title("GPU使用率").borders(Borders::ALL))
+                    .block(Block::default().title(tr(Text::GpuUsage)).borders(Borders::ALL))
                     .gauge_style(Style::default().fg(Color::Green))
-                    .label(format!("{}% ({}°C)", gpu_stats.utilization, gpu_stats.temperature))
-                    .percent(gpu_stats.utilization as u16);
+                    .label(format!(
+                        "{}% ({})",
+                        gpu_stats.utilization,
+                        format_temperature(gpu_stats.temperature as f32, self.theme.temp_unit)
+                    ))
+                    .percent(Self::gauge_percent(gpu_stats.utilization as f64));
                 frame.render_widget(gpu_usage, gpu_chunks[1]);
 
                 // 显存使用率
-                let memory_usage = (gpu_stats.memory_used as f64 / gpu_stats.memory_total as f64 * 100.0) as u16;
+                let memory_usage = DiskMonitor::usage_percentage(gpu_stats.memory_total, gpu_stats.memory_used);
                 let memory_gauge = Gauge::default()
-                    .block(Block::default().title("显存使用率").borders(Borders::ALL))
+                    .block(Block::default().title(tr(Text::VramUsage)).borders(Borders::ALL))
                     .gauge_style(Style::default().fg(Color::Green))
                     .label(format!(
-                        "已用: {} / 总计: {} ({:.1}%)",
-                        MemoryMonitor::format_bytes(gpu_stats.memory_used),
-                        MemoryMonitor::format_bytes(gpu_stats.memory_total),
-                        memory_usage as f64
+                        "{}: {} / {}: {} ({:.1}%)",
+                        tr(Text::Used),
+                        MemoryMonitor::format_bytes(gpu_stats.memory_used, self.units),
+                        tr(Text::Total),
+                        MemoryMonitor::format_bytes(gpu_stats.memory_total, self.units),
+                        memory_usage
                     ))
-                    .percent(memory_usage);
+                    .percent(Self::gauge_percent(memory_usage));
                 frame.render_widget(memory_gauge, gpu_chunks[2]);
+
+                // 温度走势，只取能放下的最近采样
+                let width = gpu_chunks[3].width.saturating_sub(2) as usize;
+                let temperatures = self.gpu_temp_history.latest(width);
+                let sparkline = Sparkline::default()
+                    .block(Block::default()
+                        .title(format!("{} ({})", tr(Text::TemperatureTrend), format_temperature(gpu_stats.temperature as f32, self.theme.temp_unit)))
+                        .borders(Borders::ALL))
+                    .data(&temperatures)
+                    .max(100)
+                    .style(Style::default().fg(if gpu_stats.temperature > 80 {
+                        Color::Red
+                    } else {
+                        Color::Green
+                    }));
+                frame.render_widget(sparkline, gpu_chunks[3]);
+
+                // 降频原因
+                let (throttle_text, throttle_color) = if gpu_stats.throttle_reasons.is_empty() {
+                    (tr(Text::NoThrottle).to_string(), Color::Green)
+                } else {
+                    (gpu_stats.throttle_text(), Color::Yellow)
+                };
+                let throttle = Paragraph::new(throttle_text)
+                    .block(Block::default().title(tr(Text::ThrottleReasons)).borders(Borders::ALL))
+                    .style(Style::default().fg(throttle_color));
+                frame.render_widget(throttle, gpu_chunks[4]);
+            }
+
+            // 健康分
+            if let Some(health) = &sample.health {
+                let (bottleneck, pressure) = health.bottleneck();
+                let bottleneck_color = if pressure > 90.0 {
+                    Color::Red
+                } else if pressure > 70.0 {
+                    Color::Yellow
+                } else {
+                    Color::Reset
+                };
+                let title = Line::from(vec![
+                    Span::raw(format!("{} │ ", tr(Text::HealthScore))),
+                    Span::styled(
+                        format!("{}: {} {:.0}%", tr(Text::Bottleneck), bottleneck, pressure),
+                        Style::default().fg(bottleneck_color).add_modifier(Modifier::BOLD),
+                    ),
+                ]);
+                let gauge = Gauge::default()
+                    .block(Block::default().title(title).borders(Borders::ALL))
+                    .gauge_style(Style::default().fg(if health.score < 40.0 {
+                        Color::Red
+                    } else if health.score < 70.0 {
+                        Color::Yellow
+                    } else {
+                        Color::Green
+                    }))
+                    .label(format!(
+                        "{:.0} {} │ CPU {:.0}% {} {:.0}% {} {:.0}% {} {:.0}%",
+                        health.score,
+                        tr(Text::Points),
+                        health.cpu,
+                        tr(Text::Memory),
+                        health.memory,
+                        tr(Text::Disk),
+                        health.disk,
+                        tr(Text::SwapShort),
+                        health.swap
+                    ))
+                    .percent(Self::gauge_percent(health.score));
+                frame.render_widget(gauge, info_chunks[0]);
             }
 
             // Memory 和 Swap 部分
-            if let Ok(mem_stats) = monitor.memory_stats() {
+            if let Some(mem_stats) = &sample.memory {
+                // 未启用交换分区时不显示交换分区仪表，空出的高度归内存仪表
+                let swap_height = if mem_stats.swap_total > 0 { 2 } else { 0 };
                 let memory_chunks = Layout::default()
                     .direction(Direction::Vertical)
                     .constraints([
-                        Constraint::Length(4),  // 增加内存使用率显示空间
-                        Constraint::Length(2),  // 减少交换分区显示空间
+                        Constraint::Length(6 - swap_height),  // 内存使用率，含交换分区空出的行
+                        Constraint::Length(4),  // 内存走势
+                        Constraint::Length(swap_height),  // 交换分区
+                        Constraint::Length(swap_details_height),  // 交换设备明细
                     ].as_ref())
-                    .split(info_chunks[0]);
+                    .split(info_chunks[1]);
 
                 // 内存使用率 - 增加显示内容
-                let memory_usage = (mem_stats.used as f64 / mem_stats.total as f64 * 100.0) as u16;
+                let memory_usage = DiskMonitor::usage_percentage(mem_stats.total, mem_stats.used);
+                let commit_info = match (mem_stats.committed, mem_stats.commit_limit) {
+                    (Some(committed), Some(limit)) => format!(
+                        " [{}: {} / {}]",
+                        tr(Text::Committed),
+                        MemoryMonitor::format_bytes(committed, self.units),
+                        MemoryMonitor::format_bytes(limit, self.units),
+                    ),
+                    _ => String::new(),
+                };
+                // Linux 上在标题中附加实际占用、缓冲和缓存的明细（标签已接近面板宽度），其他平台只显示总的已用量
+                let breakdown = if mem_stats.buffers + mem_stats.cached > 0 {
+                    format!(
+                        " [{}: {} + {}: {} + {}: {}]",
+                        tr(Text::ActualUsed),
+                        MemoryMonitor::format_bytes(mem_stats.truly_used, self.units),
+                        tr(Text::Buffers),
+                        MemoryMonitor::format_bytes(mem_stats.buffers, self.units),
+                        tr(Text::Cache),
+                        MemoryMonitor::format_bytes(mem_stats.cached, self.units),
+                    )
+                } else {
+                    String::new()
+                };
                 let memory_gauge = Gauge::default()
                     .block(Block::default()
-                        .title("内存使用情况")
-                        .borders(Borders::ALL))
-                    .gauge_style(Style::default().fg(if memory_usage > 90 {
-                        Color::Red
-                    } else if memory_usage > 70 {
-                        Color::Yellow
-                    } else {
-                        Color::Green
-                    }))
+                        .title(format!("{}{}", tr(Text::MemoryTitle), breakdown))
+                        .borders(Borders::ALL)
+                        .border_style(border(Panel::Memory)))
+                    .gauge_style(Style::default().fg(self.theme.color(memory_usage, self.theme.memory)))
                     .label(format!(
-                        "已用: {} / 总计: {} ({:.1}%) [可用: {}]",
-                        MemoryMonitor::format_bytes(mem_stats.used),
-                        MemoryMonitor::format_bytes(mem_stats.total),
-                        memory_usage as f64,
-                        MemoryMonitor::format_bytes(mem_stats.available),
+                        "{}: {} / {}: {} ({:.1}%) [{}: {}]{}",
+                        tr(Text::Used),
+                        MemoryMonitor::format_bytes(mem_stats.used, self.units),
+                        tr(Text::Total),
+                        MemoryMonitor::format_bytes(mem_stats.total, self.units),
+                        memory_usage,
+                        tr(Text::Available),
+                        MemoryMonitor::format_bytes(mem_stats.available, self.units),
+                        commit_info,
                     ))
-                    .percent(memory_usage);
+                    .percent(Self::gauge_percent(memory_usage));
 
                 // 交换分区显示
-                let swap_usage = (mem_stats.swap_used as f64 / mem_stats.swap_total as f64 * 100.0) as u16;
+                let swap_usage = mem_stats.swap_usage();
                 let swap_gauge = Gauge::default()
                     .block(Block::default()
-                        .title("交换分区")
+                        .title(tr(Text::Swap))
                         .borders(Borders::ALL))
-                    .gauge_style(Style::default().fg(if swap_usage > 50 {
-                        Color::Red
-                    } else if swap_usage > 25 {
-                        Color::Yellow
-                    } else {
-                        Color::Green
-                    }))
-                    .label(format!(
-                        "{} / {}",
-                        MemoryMonitor::format_bytes(mem_stats.swap_used),
-                        MemoryMonitor::format_bytes(mem_stats.swap_total),
-                    ))
-                    .percent(swap_usage);
+                    .gauge_style(Style::default().fg(self.theme.color(swap_usage.unwrap_or(0.0), self.theme.swap)))
+                    .label(match swap_usage {
+                        Some(_) => format!(
+                            "{} / {}",
+                            MemoryMonitor::format_bytes(mem_stats.swap_used, self.units),
+                            MemoryMonitor::format_bytes(mem_stats.swap_total, self.units),
+                        ),
+                        None => tr(Text::Disabled).to_string(),
+                    })
+                    .percent(Self::gauge_percent(swap_usage.unwrap_or(0.0)));
+
+                // 内存走势，最新值超过警告分界时随仪表变色
+                let width = memory_chunks[1].width.saturating_sub(2) as usize;
+                let memory_history = self.memory_history.latest(width);
+                let latest = memory_history.last().copied().unwrap_or(0) as f64;
+                let memory_trend = Sparkline::default()
+                    .block(Block::default().title(tr(Text::MemoryTrend)).borders(Borders::ALL))
+                    .data(&memory_history)
+                    .max(100)
+                    .style(Style::default().fg(self.theme.color(latest, self.theme.memory)));
 
                 frame.render_widget(memory_gauge, memory_chunks[0]);
-                frame.render_widget(swap_gauge, memory_chunks[1]);
+                frame.render_widget(memory_trend, memory_chunks[1]);
+                if swap_height > 0 {
+                    frame.render_widget(swap_gauge, memory_chunks[2]);
+                }
+
+                if swap_details_height > 0 {
+                    let items: Vec<ListItem> = mem_stats.swap_devices.iter()
+                        .map(|device| {
+                            ListItem::new(format!(
+                                "{} ({}, {} {}): {} / {}",
+                                device.name,
+                                device.kind,
+                                tr(Text::Priority),
+                                device.priority,
+                                MemoryMonitor::format_bytes(device.used, self.units),
+                                MemoryMonitor::format_bytes(device.size, self.units),
+                            ))
+                        })
+                        .collect();
+                    let devices = List::new(items)
+                        .block(Block::default().title(tr(Text::SwapDevices)).borders(Borders::ALL))
+                        .style(Style::default().fg(Color::Green));
+                    frame.render_widget(devices, memory_chunks[3]);
+                }
             }
 
             // Disk 部分
-            if let Ok(disk_stats) = monitor.disk_stats() {
-                let disk_area = info_chunks[1];  // 使用索引1
+            self.disk_selected = self.disk_selected.min(sample.disks.len().saturating_sub(1));
+            if self.disk_details && !sample.disks.is_empty() {
+                let disk = &sample.disks[self.disk_selected];
+                let details = Paragraph::new(Self::disk_detail_lines(disk, self.units))
+                    .block(Block::default()
+                        .title(format!("{}: {} ({})", tr(Text::DiskDetails), disk.name, tr(Text::DiskDetailsHint)))
+                        .borders(Borders::ALL)
+                        .border_style(border(Panel::Disk)))
+                    .style(Style::default().fg(if disk.error.is_some() { Color::Red } else { Color::Green }));
+                frame.render_widget(details, info_chunks[2]);
+            } else if !sample.disks.is_empty() {
+                let disk_area = info_chunks[2];  // 使用索引2
+                // 磁盘面板获得焦点时，有 inode 统计的磁盘多占一行显示 inode 使用率
+                let inode_details = self.focus == Panel::Disk;
+                let disk_height = |disk: &DiskStats| {
+                    if inode_details && disk.inodes.is_some() { 4 } else { 3 }
+                };
+                let disks_per_page = (disk_area.height / if inode_details { 4 } else { 3 }) as usize;
+                let scroll = self.scroll.entry(Panel::Disk).or_default();
+                scroll.update(disks_per_page, sample.disks.len());
+                let offset = scroll.follow(self.disk_selected);
+                let disk_stats: Vec<_> = sample.disks.iter().skip(offset).take(disks_per_page).collect();
                 let disk_chunks = Layout::default()
                     .direction(Direction::Vertical)
                     .constraints(
-                        disk_stats.iter().map(|_| Constraint::Length(3)).collect::<Vec<_>>()
+                        disk_stats.iter().map(|disk| Constraint::Length(disk_height(disk))).collect::<Vec<_>>()
                     )
                     .split(disk_area);
 
-                for (i, disk) in disk_stats.iter().enumerate() {
-                    let usage = DiskMonitor::usage_percentage(disk.total_space, disk.used_space);
-                    let disk_type = if disk.is_removable {
-                        format!("{} [可移动]", disk.disk_type)
+                for (i, disk) in disk_stats.into_iter().enumerate() {
+                    // 获得焦点时反色标出选中的磁盘，按 Enter 查看详情
+                    let title_style = if self.focus == Panel::Disk && offset + i == self.disk_selected {
+                        Style::default().add_modifier(Modifier::REVERSED)
                     } else {
-                        disk.disk_type.clone()
+                        Style::default()
+                    };
+                    let usage = DiskMonitor::usage_percentage(disk.total_space, disk.used_space);
+                    let mut disk_type = match disk.file_system.as_str() {
+                        "" => disk.disk_type.to_string(),
+                        file_system => format!("{}, {}", file_system, disk.disk_type),
+                    };
+                    if disk.is_removable {
+                        disk_type.push_str(&format!(" [{}]", tr(Text::Removable)));
+                    }
+                    if disk.is_read_only() {
+                        disk_type.push_str(" [ro]");
+                    }
+
+                    if let Some(error) = &disk.error {
+                        let gauge = Gauge::default()
+                            .block(Block::default()
+                                .title(format!("⚠ {} ({})", disk.name, disk_type))
+                                .title_style(title_style)
+                                .borders(Borders::ALL)
+                                .border_style(border(Panel::Disk)))
+                            .gauge_style(Style::default().fg(Color::Red))
+                            .label(format!("{} {}: {}", disk.mount_point, tr(Text::ReadFailed), error))
+                            .percent(0);
+                        frame.render_widget(gauge, disk_chunks[i]);
+                        continue;
+                    }
+
+                    // 剩余空间模式仍按已用比例着色：剩余越少越红
+                    let (percent, label, color) = match self.disk_display {
+                        DiskDisplay::Used => (
+                            usage,
+                            format!(
+                                "{}: {} / {}: {} ({:.1}%)",
+                                tr(Text::Used),
+                                MemoryMonitor::format_bytes(disk.used_space, self.units),
+                                tr(Text::Total),
+                                MemoryMonitor::format_bytes(disk.total_space, self.units),
+                                usage
+                            ),
+                            self.theme.color(usage, self.theme.disk),
+                        ),
+                        DiskDisplay::Free => {
+                            let free = DiskMonitor::usage_percentage(disk.total_space, disk.available_space);
+                            (
+                                free,
+                                format!(
+                                    "{}: {} / {}: {} ({:.1}%)",
+                                    tr(Text::Available),
+                                    MemoryMonitor::format_bytes(disk.available_space, self.units),
+                                    tr(Text::Total),
+                                    MemoryMonitor::format_bytes(disk.total_space, self.units),
+                                    free
+                                ),
+                                self.theme.color(usage, self.theme.disk),
+                            )
+                        }
                     };
 
+                    let label = format!(
+                        "{}  {}: {} {}: {}",
+                        label,
+                        tr(Text::ReadShort),
+                        NetworkMonitor::format_speed(disk.read_bytes_per_sec as f64, self.units),
+                        tr(Text::WriteShort),
+                        NetworkMonitor::format_speed(disk.write_bytes_per_sec as f64, self.units)
+                    );
+                    let block = Block::default()
+                        .title(format!("{} ({})", disk.name, disk_type))
+                        .title_style(title_style)
+                        .borders(Borders::ALL)
+                        .border_style(border(Panel::Disk));
+                    let inner = block.inner(disk_chunks[i]);
+                    frame.render_widget(block, disk_chunks[i]);
+                    let rows = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints([Constraint::Length(1), Constraint::Min(0)].as_ref())
+                        .split(inner);
+
                     let gauge = Gauge::default()
-                        .block(Block::default()
-                            .title(format!("{} ({})", disk.name, disk_type))
-                            .borders(Borders::ALL))
-                        .gauge_style(Style::default().fg(if usage > 90.0 {
-                            Color::Red
-                        } else if usage > 70.0 {
-                            Color::Yellow
-                        } else {
-                            Color::Green
-                        }))
-                        .label(format!(
-                            "已用: {} / 总计: {} ({:.1}%)",
-                            MemoryMonitor::format_bytes(disk.used_space),
-                            MemoryMonitor::format_bytes(disk.total_space),
-                            usage
-                        ))
-                        .percent(usage as u16);
+                        .gauge_style(Style::default().fg(color))
+                        .label(label)
+                        .percent(Self::gauge_percent(percent));
+                    frame.render_widget(gauge, rows[0]);
 
-                    frame.render_widget(gauge, disk_chunks[i]);
+                    if let Some(inodes) = disk.inodes.filter(|_| inode_details) {
+                        let inode_usage = DiskMonitor::usage_percentage(inodes.total, inodes.used);
+                        let gauge = Gauge::default()
+                            .gauge_style(Style::default().fg(self.theme.color(inode_usage, self.theme.disk)))
+                            .label(format!(
+                                "inode: {} / {} ({:.1}%)",
+                                inodes.used, inodes.total, inode_usage
+                            ))
+                            .percent(Self::gauge_percent(inode_usage));
+                        frame.render_widget(gauge, rows[1]);
+                    }
                 }
             }
 
             // Network 部分
-            if let Ok(net_stats) = monitor.network_stats() {
-                let net_area = info_chunks[2];  // 使用索引2
-                let net_list_items: Vec<ListItem> = net_stats.iter()
-                    .map(|net| {
-                        ListItem::new(format!(
-                            "{}: ↓{}/s ↑{}/s (总计: ↓{} ↑{})",
-                            net.interface_name,
-                            NetworkMonitor::format_speed(net.received_bytes as f64),
-                            NetworkMonitor::format_speed(net.transmitted_bytes as f64),
-                            MemoryMonitor::format_bytes(net.total_received),
-                            MemoryMonitor::format_bytes(net.total_transmitted),
-                        ))
-                    })
-                    .collect();
+            if !sample.networks.is_empty() {
+                let net_stats = &sample.networks;
+                let net_area = info_chunks[3];  // 使用索引3
+                let mut net_list_items: Vec<ListItem> = Vec::new();
+                if let Some(tcp) = &sample.tcp {
+                    net_list_items.push(ListItem::new(format!(
+                        "{}: {} {} │ {} {} │ {} {} │ TIME_WAIT {} │ CLOSE_WAIT {}",
+                        tr(Text::TcpConnections),
+                        tr(Text::Established),
+                        tcp.established,
+                        tr(Text::Listen),
+                        tcp.listen,
+                        tr(Text::Handshake),
+                        tcp.syn,
+                        tcp.time_wait,
+                        tcp.close_wait,
+                    )).style(Style::default().fg(Color::Cyan)));
+                }
+                // 网络面板获得焦点时在每个接口下显示包数和错误数（有错误时标红）以及 IP 地址
+                let details = self.focus == Panel::Network;
+                let packet_item = |net: &NetworkStats, indent: &str| {
+                    let style = if NetworkMonitor::has_errors(net) {
+                        Style::default().fg(Color::Red)
+                    } else {
+                        Style::default().fg(Color::DarkGray)
+                    };
+                    ListItem::new(format!("{}  {}", indent, NetworkMonitor::packet_summary(net))).style(style)
+                };
+                // 带宽条按接收、发送中较大的一方计算；指定 --net-max 时按占用率变色
+                let net_item = |net: &NetworkStats, indent: &str, suffix: &str, style: Style| {
+                    let (rx, tx) = if self.averaged {
+                        (net.average_received_bytes, net.average_transmitted_bytes)
+                    } else {
+                        (net.received_bytes, net.transmitted_bytes)
+                    };
+                    let scale = self.net_max
+                        .or_else(|| self.net_peaks.get(&net.interface_name).copied())
+                        .unwrap_or(0);
+                    let usage = DiskMonitor::usage_percentage(scale, rx.max(tx));
+                    let filled = (Self::gauge_percent(usage) as usize) / 10;
+                    let style = match self.net_max {
+                        Some(_) => style.fg(self.theme.color(usage, NET_LEVELS)),
+                        None => style,
+                    };
+                    ListItem::new(format!(
+                        "{}[{}{}] {:3.0}% {}{}",
+                        indent,
+                        "█".repeat(filled),
+                        "░".repeat(10 - filled),
+                        usage,
+                        Self::network_line(net, self.averaged, self.units, self.net_bits),
+                        suffix
+                    )).style(style)
+                };
+                let address_item = |net: &NetworkStats, indent: &str| {
+                    ListItem::new(format!("{}  {}", indent, NetworkMonitor::address_summary(net)))
+                        .style(Style::default().fg(Color::DarkGray))
+                };
+                for net in net_stats {
+                    let collapsed = if net.children.is_empty() || self.net_children {
+                        String::new()
+                    } else {
+                        format!(" [+{}]", net.children.len())
+                    };
+                    net_list_items.push(net_item(net, "", &collapsed, Style::default()));
+                    if details {
+                        net_list_items.push(packet_item(net, ""));
+                        if NetworkMonitor::ADDRESSES_SUPPORTED {
+                            net_list_items.push(address_item(net, ""));
+                        }
+                    }
+                    if self.net_children {
+                        for child in &net.children {
+                            net_list_items.push(net_item(child, "  └ ", "", Style::default()));
+                            if details {
+                                net_list_items.push(packet_item(child, "   "));
+                                if NetworkMonitor::ADDRESSES_SUPPORTED {
+                                    net_list_items.push(address_item(child, "   "));
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // 合计行不含回环接口
+                let total = NetworkMonitor::aggregate(net_stats);
+                net_list_items.push(net_item(&total, "", "", Style::default().add_modifier(Modifier::BOLD)));
+                if details {
+                    net_list_items.push(packet_item(&total, ""));
+                }
 
-                let net_list = List::new(net_list_items)
+                let lines_per_page = net_area.height.saturating_sub(2) as usize;
+                let offset = self.scroll.entry(Panel::Network).or_default().update(lines_per_page, net_list_items.len());
+                let net_list = List::new(net_list_items.into_iter().skip(offset).collect::<Vec<_>>())
                     .block(Block::default()
-                        .title("网络接口状态")
-                        .borders(Borders::ALL))
+                        .title(tr(Text::NetworkInterfaces))
+                        .borders(Borders::ALL)
+                        .border_style(border(Panel::Network)))
                     .style(Style::default().fg(Color::Blue));
 
                 frame.render_widget(net_list, net_area);
             }
+
+            // 内核熵池
+            if let Some(entropy) = &sample.entropy {
+                let usage = DiskMonitor::usage_percentage(entropy.pool_size, entropy.available);
+                let gauge = Gauge::default()
+                    .block(Block::default().title(tr(Text::EntropyPool)).borders(Borders::ALL))
+                    .gauge_style(Style::default().fg(if usage < 10.0 {
+                        Color::Red
+                    } else if usage < 25.0 {
+                        Color::Yellow
+                    } else {
+                        Color::Green
+                    }))
+                    .label(format!("{} / {} bits", entropy.available, entropy.pool_size))
+                    .percent(Self::gauge_percent(usage));
+                frame.render_widget(gauge, info_chunks[4]);
+            }
+
+            // RAID 阵列，失去冗余的阵列标红
+            if let Some(arrays) = &sample.raid {
+                let raid_area = info_chunks[4 + sample.entropy.is_some() as usize];
+                let items: Vec<ListItem> = if arrays.is_empty() {
+                    vec![ListItem::new(tr(Text::NoRaid))]
+                } else {
+                    arrays.iter()
+                        .map(|array| {
+                            let (marker, color) = if array.is_degraded() {
+                                (format!("✗ {}", tr(Text::Degraded)), Color::Red)
+                            } else {
+                                (format!("✓ {}", tr(Text::Healthy)), Color::Green)
+                            };
+                            ListItem::new(format!(
+                                "{} {} {} [{}/{}] {}",
+                                array.name,
+                                array.level,
+                                array.state,
+                                array.active_disks,
+                                array.total_disks,
+                                marker
+                            )).style(Style::default().fg(color))
+                        })
+                        .collect()
+                };
+                let list = List::new(items)
+                    .block(Block::default().title(tr(Text::RaidArrays)).borders(Borders::ALL));
+                frame.render_widget(list, raid_area);
+            }
+
+            // TCP 连接表，位于进程表之上
+            if let Some(connections) = &sample.connections {
+                let connection_area = info_chunks[info_chunks.len() - 2];
+                let rows_per_page = connection_area.height.saturating_sub(3) as usize;
+                let offset = self.scroll.entry(Panel::Connections).or_default().update(rows_per_page, connections.len());
+                let rows = connections.iter()
+                    .skip(offset)
+                    .take(rows_per_page)
+                    .map(|connection| Row::new(vec![
+                        connection.local.clone(),
+                        connection.remote.clone(),
+                        connection.state.clone(),
+                        match (connection.pid, &connection.process) {
+                            (Some(pid), Some(name)) => format!("{} {}", pid, name),
+                            (Some(pid), None) => pid.to_string(),
+                            _ => "-".to_string(),
+                        },
+                    ]));
+                let header = Row::new(vec![tr(Text::LocalAddress), tr(Text::RemoteAddress), tr(Text::Status), tr(Text::Process)])
+                    .style(Style::default().add_modifier(Modifier::BOLD));
+                let widths = [
+                    Constraint::Min(21),
+                    Constraint::Min(21),
+                    Constraint::Length(11),
+                    Constraint::Min(12),
+                ];
+                let table = Table::new(rows)
+                    .header(header)
+                    .widths(&widths)
+                    .block(Block::default()
+                        .title(format!("{} ({})", tr(Text::TcpConnections), connections.len()))
+                        .borders(Borders::ALL)
+                        .border_style(border(Panel::Connections)))
+                    .style(Style::default().fg(Color::LightBlue));
+                frame.render_widget(table, connection_area);
+            }
+
+            // 进程表
+            if !sample.processes.is_empty() {
+                let process_area = info_chunks[info_chunks.len() - 1];
+                let processes = ProcessMonitor::tree_rows(&sample.processes, self.process_sort);
+
+                let rows_per_page = process_area.height.saturating_sub(3) as usize;
+                let offset = self.scroll.entry(Panel::Process).or_default().update(rows_per_page, processes.len());
+                let rows = processes.iter()
+                    .skip(offset)
+                    .take(rows_per_page)
+                    .map(|(depth, process)| Row::new(vec![
+                        process.pid.to_string(),
+                        ProcessMonitor::tree_name(*depth, process),
+                        format!("{:.1}", process.cpu_usage),
+                        ProcessMonitor::last_cpu(process.pid).map_or("-".to_string(), |cpu| cpu.to_string()),
+                        MemoryMonitor::format_bytes(process.memory, self.units),
+                        format!(
+                            "{} / {}",
+                            NetworkMonitor::format_speed(process.disk_read as f64, self.units),
+                            NetworkMonitor::format_speed(process.disk_write as f64, self.units)
+                        ),
+                    ]));
+                let header = Row::new(vec!["PID", tr(Text::Name), "CPU%", "CPU#", tr(Text::Memory), "DISK R/W"])
+                    .style(Style::default().add_modifier(Modifier::BOLD));
+                let widths = [
+                    Constraint::Length(8),
+                    Constraint::Min(12),
+                    Constraint::Length(7),
+                    Constraint::Length(5),
+                    Constraint::Length(11),
+                    Constraint::Length(25),
+                ];
+                let table = Table::new(rows)
+                    .header(header)
+                    .widths(&widths)
+                    .block(Block::default()
+                        .title(fill(Text::ProcessTitle, &[self.process_sort.label()]))
+                        .borders(Borders::ALL)
+                        .border_style(border(Panel::Process)))
+                    .style(Style::default().fg(Color::Magenta));
+                frame.render_widget(table, process_area);
+            }
+
+            // 连续采集失败的子系统在其面板的位置显示原因，而不是留下空白
+            for (subsystem, reason) in &sample.unavailable {
+                let (title, area, missing) = match subsystem {
+                    Subsystem::Cpu => (tr(Text::OverallCpuUsage), left_chunks[1], sample.cpu.is_none()),
+                    Subsystem::Gpu => (tr(Text::GpuInfo), left_chunks[4], sample.gpu.is_none()),
+                    Subsystem::Memory => (tr(Text::MemoryTitle), info_chunks[1], sample.memory.is_none()),
+                    Subsystem::Disk => (tr(Text::Disk), info_chunks[2], sample.disks.is_empty()),
+                    Subsystem::Network => (tr(Text::NetworkInterfaces), info_chunks[3], sample.networks.is_empty()),
+                };
+                if missing {
+                    let placeholder = Paragraph::new(fill(Text::Unavailable, &[reason]))
+                        .block(Block::default().title(title).borders(Borders::ALL))
+                        .style(Style::default().fg(Color::Yellow));
+                    frame.render_widget(placeholder, area);
+                }
+            }
+
+            if !self.color {
+                Self::strip_colors(frame.buffer_mut());
+            }
         })?;
 
+        if self.snapshot_requested {
+            self.snapshot_requested = false;
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            let path = format!("litemon-snapshot-{}.txt", timestamp);
+            let message = match Self::write_snapshot(completed.buffer, &path, self.color) {
+                Ok(()) => format!("{}: {}", tr(Text::SnapshotSaved), path),
+                Err(e) => format!("{}: {}", tr(Text::SnapshotFailed), e),
+            };
+            self.flash = Some((message, Instant::now()));
+        }
+
+        Ok(())
+    }
+
+    // 远程主机无数据时只显示标签页和原因
+    pub fn draw_offline(&mut self, message: &str) -> Result<()> {
+        self.terminal.draw(|frame| {
+            let mut size = frame.size();
+            if let Some(tabs) = &self.tabs {
+                size = Self::render_tabs(frame, size, tabs);
+            }
+            let paragraph = Paragraph::new(message)
+                .block(Block::default().title(tr(Text::Status)).borders(Borders::ALL))
+                .style(Style::default().fg(Color::Red));
+            frame.render_widget(paragraph, size);
+            if !self.color {
+                Self::strip_colors(frame.buffer_mut());
+            }
+        })?;
         Ok(())
     }
 
+    // 在顶部绘制一行标签页，返回剩余区域
+    fn render_tabs(frame: &mut Frame, area: Rect, (titles, active): &(Vec<String>, usize)) -> Rect {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)].as_ref())
+            .split(area);
+        let tabs = Tabs::new(titles.iter().map(|title| Line::from(title.as_str())).collect())
+            .select(*active)
+            .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::REVERSED));
+        frame.render_widget(tabs, rows[0]);
+        rows[1]
+    }
+
+    // 不使用颜色时去掉画面中的前景色和背景色，只保留粗体、反色等属性；
+    // 有背景色的单元格（仪表填充部分上的标签）改为反色，进度仍然可见
+    fn strip_colors(buffer: &mut Buffer) {
+        for cell in &mut buffer.content {
+            if cell.bg != Color::Reset {
+                cell.modifier.insert(Modifier::REVERSED);
+            }
+            cell.fg = Color::Reset;
+            cell.bg = Color::Reset;
+        }
+    }
+
+    // 将画面缓冲区逐行写成带 ANSI 颜色的文本（不使用颜色时为纯文本），便于附在问题报告中
+    fn write_snapshot(buffer: &Buffer, path: &str, color: bool) -> io::Result<()> {
+        let mut output = String::new();
+        let width = buffer.area.width as usize;
+
+        for row in buffer.content.chunks(width) {
+            let mut current: Option<(Color, Color)> = None;
+            for cell in visible_cells(row) {
+                if color && current != Some((cell.fg, cell.bg)) {
+                    output.push_str(&format!(
+                        "\x1b[0;{};{}m",
+                        Self::ansi_color(cell.fg, true),
+                        Self::ansi_color(cell.bg, false)
+                    ));
+                    current = Some((cell.fg, cell.bg));
+                }
+                output.push_str(&cell.symbol);
+            }
+            output.push_str(if color { "\x1b[0m\n" } else { "\n" });
+        }
+
+        let mut file = std::fs::File::create(path)?;
+        file.write_all(output.as_bytes())
+    }
+
+    fn ansi_color(color: Color, foreground: bool) -> String {
+        let base = if foreground { 30 } else { 40 };
+        match color {
+            Color::Reset => (base + 9).to_string(),
+            Color::Black => base.to_string(),
+            Color::Red => (base + 1).to_string(),
+            Color::Green => (base + 2).to_string(),
+            Color::Yellow => (base + 3).to_string(),
+            Color::Blue => (base + 4).to_string(),
+            Color::Magenta => (base + 5).to_string(),
+            Color::Cyan => (base + 6).to_string(),
+            Color::Gray => (base + 7).to_string(),
+            Color::DarkGray => (base + 60).to_string(),
+            Color::LightRed => (base + 61).to_string(),
+            Color::LightGreen => (base + 62).to_string(),
+            Color::LightYellow => (base + 63).to_string(),
+            Color::LightBlue => (base + 64).to_string(),
+            Color::LightMagenta => (base + 65).to_string(),
+            Color::LightCyan => (base + 66).to_string(),
+            Color::White => (base + 67).to_string(),
+            Color::Indexed(i) => format!("{};5;{}", base + 8, i),
+            Color::Rgb(r, g, b) => format!("{};2;{};{};{}", base + 8, r, g, b),
+        }
+    }
+
     pub fn cleanup(&mut self) -> Result<()> {
         disable_raw_mode()?;
         execute!(
@@ -328,24 +1305,157 @@ impl Tui {
         Ok(())
     }
 
-    fn create_core_list_item(index: usize, usage: f32, freq: u64) -> ListItem<'static> {
+    fn network_line(net: &NetworkStats, averaged: bool, units: UnitStyle, bits: bool) -> String {
+        let (rx, tx) = if averaged {
+            (net.average_received_bytes, net.average_transmitted_bytes)
+        } else {
+            (net.received_bytes, net.transmitted_bytes)
+        };
+        format!(
+            "{}: ↓{} ↑{} ({}: ↓{} ↑{})",
+            net.interface_name,
+            NetworkMonitor::format_net_speed(rx as f64, units, bits),
+            NetworkMonitor::format_net_speed(tx as f64, units, bits),
+            tr(Text::Total),
+            MemoryMonitor::format_bytes(net.total_received, units),
+            MemoryMonitor::format_bytes(net.total_transmitted, units),
+        )
+    }
+
+    fn cpu_info_lines(info: &CpuInfo, details: bool, units: UnitStyle) -> String {
+        let mut lines = vec![info.brand.clone()];
+        if details {
+            lines.push(format!("{}: {}", tr(Text::Frequency), format_frequency(info.frequency)));
+
+            let mut ids = vec![format!("{}: {}", tr(Text::Vendor), info.vendor)];
+            if let Some(family) = &info.family {
+                ids.push(format!("{}: {}", tr(Text::Family), family));
+            }
+            if let Some(model) = &info.model {
+                ids.push(format!("{}: {}", tr(Text::Model), model));
+            }
+            lines.push(ids.join(" │ "));
+
+            let caches: Vec<String> = [
+                ("L1d", info.l1d_cache),
+                ("L1i", info.l1i_cache),
+                ("L2", info.l2_cache),
+                ("L3", info.l3_cache),
+            ]
+            .iter()
+            .filter_map(|(name, size)| size.map(|size| format!("{} {}", name, MemoryMonitor::format_bytes(size, units))))
+            .collect();
+            if !caches.is_empty() {
+                lines.push(format!("{}: {}", tr(Text::Cache), caches.join(" │ ")));
+            }
+        }
+        lines.join("\n")
+    }
+
+    // 磁盘详情视图的内容，读取失败的磁盘只显示挂载信息和原因
+    fn disk_detail_lines(disk: &DiskStats, units: UnitStyle) -> String {
+        let yes_no = |value: bool| tr(if value { Text::Yes } else { Text::No });
+        let mut lines = vec![
+            format!("{}: {}", tr(Text::Device), disk.name),
+            format!("{}: {}", tr(Text::MountPoint), disk.mount_point),
+            format!(
+                "{}: {} ({})",
+                tr(Text::FileSystem),
+                if disk.file_system.is_empty() { tr(Text::Unknown) } else { &disk.file_system },
+                disk.disk_type
+            ),
+            format!("{}: {}", tr(Text::Removable), yes_no(disk.is_removable)),
+            format!("{}: {}", tr(Text::ReadOnly), yes_no(disk.is_read_only())),
+        ];
+        if !disk.mount_options.is_empty() {
+            lines.push(format!("{}: {}", tr(Text::MountOptions), disk.mount_options.join(",")));
+        }
+        lines.push(String::new());
+
+        if let Some(error) = &disk.error {
+            lines.push(format!("{}: {}", tr(Text::ReadFailed), error));
+            return lines.join("\n");
+        }
+        lines.extend([
+            format!("{}: {}", tr(Text::Capacity), MemoryMonitor::format_bytes(disk.total_space, units)),
+            format!(
+                "{}: {} ({:.1}%)",
+                tr(Text::Used),
+                MemoryMonitor::format_bytes(disk.used_space, units),
+                DiskMonitor::usage_percentage(disk.total_space, disk.used_space)
+            ),
+            format!("{}: {}", tr(Text::Available), MemoryMonitor::format_bytes(disk.available_space, units)),
+            format!("{}: {}", tr(Text::Read), NetworkMonitor::format_speed(disk.read_bytes_per_sec as f64, units)),
+            format!("{}: {}", tr(Text::Write), NetworkMonitor::format_speed(disk.write_bytes_per_sec as f64, units)),
+        ]);
+        if let Some(inodes) = &disk.inodes {
+            let usage = format!(
+                "{} / {} ({:.1}%)",
+                inodes.used,
+                inodes.total,
+                DiskMonitor::usage_percentage(inodes.total, inodes.used)
+            );
+            lines.push(fill(Text::InodeLine, &[&usage, &inodes.free.to_string()]));
+        }
+        lines.join("\n")
+    }
+
+    // ratatui 的 Gauge 要求百分比不超过 100，超出的真实值只在标签中显示
+    fn gauge_percent(value: f64) -> u16 {
+        value.clamp(0.0, 100.0) as u16
+    }
+
+    fn create_core_list_item(theme: &Theme, index: usize, usage: f32, freq: String) -> ListItem<'static> {
+        let filled = (Self::gauge_percent(usage as f64) as usize) / 5;
         let usage_gauge = format!(
             "{:3.1}% [{}{}]",
             usage,
-            "█".repeat((usage * 0.2) as usize),
-            "░".repeat((20.0 - usage * 0.2) as usize)
+            "█".repeat(filled),
+            "░".repeat(20 - filled)
         );
         ListItem::new(format!(
-            "核心 #{:2}: {} │ {:.1} GHz",
+            "{} #{:2}: {} │ {}",
+            tr(Text::Core),
             index,
             usage_gauge,
-            freq as f64 / 1000.0
-        )).style(Style::default().fg(if usage > 80.0 {
-            Color::Red
-        } else if usage > 50.0 {
-            Color::Yellow
-        } else {
-            Color::Green
-        }))
+            freq
+        )).style(Style::default().fg(theme.color(usage as f64, theme.cpu)))
     }
-} 
\ No newline at end of file
+} 
+
+// 缓冲区一行中实际显示的单元格：宽字符之后的单元格为占位，跳过
+fn visible_cells(row: &[Cell]) -> impl Iterator<Item = &Cell> {
+    let mut skip = 0;
+    row.iter().filter(move |cell| {
+        if skip > 0 {
+            skip -= 1;
+            return false;
+        }
+        skip = unicode_width::UnicodeWidthStr::width(cell.symbol.as_str()).saturating_sub(1);
+        true
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUT_OF_RANGE: [f64; 6] = [100.5, 150.0, 1e12, f64::INFINITY, -3.0, f64::NAN];
+
+    #[test]
+    fn gauge_percent_stays_within_range() {
+        assert_eq!(Tui::gauge_percent(42.7), 42);
+        assert_eq!(Tui::gauge_percent(100.0), 100);
+        for value in OUT_OF_RANGE {
+            assert!(Tui::gauge_percent(value) <= 100, "{}", value);
+            // ratatui 的 Gauge 在百分比超过 100 时 panic
+            let _ = Gauge::default().percent(Tui::gauge_percent(value));
+        }
+    }
+
+    #[test]
+    fn core_list_item_over_100_percent() {
+        let _ = Tui::create_core_list_item(&Theme::default(), 0, 130.0, String::new());
+        let _ = Tui::create_core_list_item(&Theme::default(), 0, -1.0, String::new());
+    }
+}
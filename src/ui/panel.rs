@@ -0,0 +1,83 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+/// 可获得焦点的面板，Tab 按此顺序切换，滚动键作用于获得焦点的面板
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Panel {
+    Cpu,
+    Memory,
+    Disk,
+    Network,
+    Connections,
+    Process,
+    Gpu,
+}
+
+/// 一个列表的滚动状态。可见行数和总行数在每次绘制时更新
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Scroll {
+    pub offset: usize,
+    page_size: usize,
+    len: usize,
+}
+
+impl Scroll {
+    // 记录本次绘制的可见行数和总行数，把偏移限制在有效范围内并返回
+    pub fn update(&mut self, page_size: usize, len: usize) -> usize {
+        self.page_size = page_size;
+        self.len = len;
+        self.offset = self.offset.min(len.saturating_sub(page_size));
+        self.offset
+    }
+
+    // 带选中行的列表：按键移动选中行并返回新位置，偏移随之调整使选中行保持可见
+    pub fn select(&mut self, selected: usize, key: KeyEvent) -> usize {
+        let last = self.len.saturating_sub(1);
+        let page = self.page_size.max(1);
+        let selected = match key.code {
+            KeyCode::Up => selected.saturating_sub(1),
+            KeyCode::Down => (selected + 1).min(last),
+            KeyCode::PageUp => selected.saturating_sub(page),
+            KeyCode::PageDown => (selected + page).min(last),
+            KeyCode::Home => 0,
+            KeyCode::End => last,
+            _ => selected,
+        };
+        self.follow(selected);
+        selected
+    }
+
+    // 调整偏移使第 index 行落在可见范围内
+    pub fn follow(&mut self, index: usize) -> usize {
+        if index < self.offset {
+            self.offset = index;
+        } else if self.page_size > 0 && index >= self.offset + self.page_size {
+            self.offset = index + 1 - self.page_size;
+        }
+        self.offset
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        let max_scroll = self.len.saturating_sub(self.page_size);
+        match key.code {
+            KeyCode::Up => {
+                self.offset = self.offset.saturating_sub(1);
+            }
+            KeyCode::Down if self.offset < max_scroll => {
+                self.offset += 1;
+            }
+            KeyCode::PageUp => {
+                self.offset = self.offset.saturating_sub(self.page_size);
+            }
+            KeyCode::PageDown => {
+                self.offset = (self.offset + self.page_size).min(max_scroll);
+            }
+            KeyCode::Home => {
+                self.offset = 0;
+            }
+            KeyCode::End => {
+                self.offset = max_scroll;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    text::Line,
+    widgets::{Row, Table, Widget},
+};
+use unicode_width::UnicodeWidthStr;
+use super::visible_cells;
+
+/// 纯文本对齐表格：由 ratatui 的 Table 排版到缓冲区再逐行取出，适合输出到分页器或文件
+pub struct TextTable {
+    columns: Vec<(String, Alignment)>,
+    rows: Vec<Vec<String>>,
+}
+
+impl TextTable {
+    pub fn new(columns: &[(&str, Alignment)]) -> Self {
+        Self {
+            columns: columns.iter().map(|(title, align)| (title.to_string(), *align)).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    // 表头、分隔线和各行，列之间以两个空格隔开，行尾不留空格
+    pub fn render(&self) -> Vec<String> {
+        // 每列取标题和各单元格中最宽者（按显示宽度，中文占两列）
+        let widths: Vec<u16> = self.columns.iter()
+            .enumerate()
+            .map(|(i, (title, _))| {
+                self.rows.iter()
+                    .filter_map(|row| row.get(i))
+                    .map(|cell| cell.width())
+                    .fold(title.width(), usize::max) as u16
+            })
+            .collect();
+        let constraints: Vec<Constraint> = widths.iter().map(|&width| Constraint::Length(width)).collect();
+
+        let row = |cells: Vec<String>| {
+            Row::new(cells.into_iter()
+                .zip(&self.columns)
+                .map(|(cell, (_, align))| Line::from(cell).alignment(*align)))
+        };
+        let header = row(self.columns.iter().map(|(title, _)| title.clone()).collect());
+        let separator = row(widths.iter().map(|&width| "─".repeat(width as usize)).collect());
+        let rows: Vec<Row> = [header, separator].into_iter()
+            .chain(self.rows.iter().cloned().map(row))
+            .collect();
+
+        let width = widths.iter().sum::<u16>() + 2 * (widths.len() as u16).saturating_sub(1);
+        let area = Rect::new(0, 0, width.max(1), rows.len() as u16);
+        let mut buffer = Buffer::empty(area);
+        Table::new(rows).widths(&constraints).column_spacing(2).render(area, &mut buffer);
+
+        buffer.content
+            .chunks(area.width as usize)
+            .map(|line| {
+                let text: String = visible_cells(line).map(|cell| cell.symbol.as_str()).collect();
+                text.trim_end().to_string()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aligns_by_display_width() {
+        let mut table = TextTable::new(&[("设备", Alignment::Left), ("Used", Alignment::Right)]);
+        table.push(vec!["sda1".to_string(), "1.5 GiB".to_string()]);
+        table.push(vec!["磁盘二".to_string(), "0 B".to_string()]);
+        assert_eq!(table.render(), [
+            "设备       Used",
+            "──────  ───────",
+            "sda1    1.5 GiB",
+            "磁盘二      0 B",
+        ]);
+    }
+}
@@ -0,0 +1,56 @@
+use ratatui::style::Color;
+use crate::cli::TempUnit;
+
+/// 一个指标的告警分界（百分比）：超过 warn 显示警告色，超过 critical 显示严重色
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Levels {
+    pub warn: f64,
+    pub critical: f64,
+}
+
+impl Levels {
+    pub const fn new(warn: f64, critical: f64) -> Self {
+        Self { warn, critical }
+    }
+}
+
+/// 仪表配色。默认值与原先写死的分界和颜色一致，可在配置文件的 [theme] 中修改，
+/// 例如为色盲用户换成蓝/橙配色。温度单位也放在这里，TUI 和文本输出按同一设置显示
+#[derive(Debug, Clone)]
+pub struct Theme {
+    pub cpu: Levels,
+    pub memory: Levels,
+    pub disk: Levels,
+    pub swap: Levels,
+    pub normal: Color,
+    pub warn: Color,
+    pub critical: Color,
+    pub temp_unit: TempUnit,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            cpu: Levels::new(50.0, 80.0),
+            memory: Levels::new(70.0, 90.0),
+            disk: Levels::new(70.0, 90.0),
+            swap: Levels::new(25.0, 50.0),
+            normal: Color::Green,
+            warn: Color::Yellow,
+            critical: Color::Red,
+            temp_unit: TempUnit::Celsius,
+        }
+    }
+}
+
+impl Theme {
+    pub fn color(&self, usage: f64, levels: Levels) -> Color {
+        if usage > levels.critical {
+            self.critical
+        } else if usage > levels.warn {
+            self.warn
+        } else {
+            self.normal
+        }
+    }
+}
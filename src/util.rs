@@ -0,0 +1,175 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use crate::cli::TempUnit;
+
+/// 将时长格式化为 "3d 4h 12m" 的形式，不足一分钟时显示秒数
+pub fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let (days, hours, minutes) = (seconds / 86400, seconds % 86400 / 3600, seconds % 3600 / 60);
+
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m", minutes)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+/// 将摄氏温度按所选单位格式化为 "45°C" 或 "113°F"，均取整到一度
+pub fn format_temperature(celsius: f32, unit: TempUnit) -> String {
+    match unit {
+        TempUnit::Celsius => format!("{:.0}°C", celsius),
+        TempUnit::Fahrenheit => format!("{:.0}°F", celsius * 9.0 / 5.0 + 32.0),
+    }
+}
+
+/// 将频率（MHz）格式化为 "2.4 GHz"，0 表示平台未提供读数，显示为 N/A
+pub fn format_frequency(mhz: u64) -> String {
+    match mhz {
+        0 => "N/A".to_string(),
+        mhz => format!("{:.1} GHz", mhz as f64 / 1000.0),
+    }
+}
+
+/// 当前频率和最高频率，如 "2.4/3.6 GHz"；最高频率未知时只显示当前频率
+pub fn format_core_frequency(current: u64, max: u64) -> String {
+    match (current, max) {
+        (0, _) | (_, 0) => format_frequency(current),
+        (current, max) => format!("{:.1}/{:.1} GHz", current as f64 / 1000.0, max as f64 / 1000.0),
+    }
+}
+
+/// ISO-8601 UTC 时间，如 2024-01-31T08:00:00Z；按公历由天数推算日期，避免引入日期库
+pub fn format_timestamp(timestamp: SystemTime) -> String {
+    let seconds = timestamp.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let (days, time) = (seconds / 86400, seconds % 86400);
+
+    // 以 0000-03-01 为纪元，每 400 年（146097 天）为一个周期
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year, month, day, time / 3600, time % 3600 / 60, time % 60
+    )
+}
+
+/// 简单通配符匹配：* 匹配任意个字符，? 匹配一个字符
+pub fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;  // 最近一个 * 的位置及其已匹配到的名称位置
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_zero() {
+        assert_eq!(format_duration(Duration::ZERO), "0s");
+    }
+
+    #[test]
+    fn duration_under_a_minute() {
+        assert_eq!(format_duration(Duration::from_secs(59)), "59s");
+        assert_eq!(format_duration(Duration::from_millis(1_999)), "1s");
+    }
+
+    #[test]
+    fn duration_minutes_and_hours() {
+        assert_eq!(format_duration(Duration::from_secs(60)), "1m");
+        assert_eq!(format_duration(Duration::from_secs(3_599)), "59m");
+        assert_eq!(format_duration(Duration::from_secs(3_600)), "1h 0m");
+        assert_eq!(format_duration(Duration::from_secs(5 * 3_600 + 7 * 60 + 30)), "5h 7m");
+    }
+
+    #[test]
+    fn duration_multiple_days() {
+        assert_eq!(format_duration(Duration::from_secs(86_400)), "1d 0h 0m");
+        assert_eq!(format_duration(Duration::from_secs(3 * 86_400 + 4 * 3_600 + 12 * 60 + 5)), "3d 4h 12m");
+        assert_eq!(format_duration(Duration::from_secs(400 * 86_400)), "400d 0h 0m");
+    }
+
+    #[test]
+    fn glob_prefix_patterns() {
+        assert!(glob_match("veth*", "veth1a2b3c"));
+        assert!(glob_match("veth*", "veth"));
+        assert!(glob_match("docker*", "docker0"));
+        assert!(!glob_match("docker*", "br-docker"));
+        assert!(!glob_match("veth*", "eth0"));
+    }
+
+    #[test]
+    fn glob_wildcards() {
+        assert!(glob_match("*", ""));
+        assert!(glob_match("eth?", "eth0"));
+        assert!(!glob_match("eth?", "eth10"));
+        assert!(glob_match("*docker*", "br-docker-1"));
+        assert!(glob_match("/boot*", "/boot/efi"));
+        assert!(glob_match("a*b*c", "axxbyyc"));
+        assert!(!glob_match("a*b*c", "axxbyy"));
+        assert!(glob_match("lo", "lo"));
+        assert!(!glob_match("lo", "lo0"));
+    }
+
+    #[test]
+    fn zero_frequency_is_not_available() {
+        assert_eq!(format_frequency(0), "N/A");
+        assert_eq!(format_core_frequency(0, 3_600), "N/A");
+        assert_eq!(format_core_frequency(0, 0), "N/A");
+    }
+
+    #[test]
+    fn core_frequency() {
+        assert_eq!(format_frequency(2_400), "2.4 GHz");
+        assert_eq!(format_core_frequency(2_400, 3_600), "2.4/3.6 GHz");
+        assert_eq!(format_core_frequency(2_400, 0), "2.4 GHz");
+    }
+
+    #[test]
+    fn fahrenheit_conversion() {
+        assert_eq!(format_temperature(0.0, TempUnit::Fahrenheit), "32°F");
+        assert_eq!(format_temperature(100.0, TempUnit::Fahrenheit), "212°F");
+        assert_eq!(format_temperature(-40.0, TempUnit::Fahrenheit), "-40°F");
+        assert_eq!(format_temperature(37.0, TempUnit::Fahrenheit), "99°F");
+    }
+
+    #[test]
+    fn celsius_rounds_to_whole_degrees() {
+        assert_eq!(format_temperature(45.4, TempUnit::Celsius), "45°C");
+        assert_eq!(format_temperature(0.0, TempUnit::Celsius), "0°C");
+    }
+}
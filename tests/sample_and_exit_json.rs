@@ -0,0 +1,38 @@
+use std::process::{Command, Output};
+
+fn litemon(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_litemon"))
+        .args(["--sample-and-exit-json", "--no-gpu"])
+        .args(args)
+        .env("XDG_CONFIG_HOME", env!("CARGO_TARGET_TMPDIR"))  // 不读取用户的配置文件
+        .output()
+        .expect("无法启动 litemon")
+}
+
+fn json(output: &Output) -> serde_json::Value {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.lines().count(), 1, "应只输出一行: {}", stdout);
+    serde_json::from_str(&stdout).expect("输出不是合法的 JSON")
+}
+
+#[test]
+fn prints_one_complete_sample() {
+    let output = litemon(&[]);
+    assert!(output.status.success());
+    let sample = json(&output);
+    assert_eq!(sample["schema_version"], 1);
+    assert!(sample["timestamp"].is_u64());
+    assert!(sample["cpu"]["total_usage"].is_number());
+    assert!(sample["memory"]["total"].as_u64().is_some_and(|total| total > 0));
+    assert!(sample["disks"].is_array());
+    assert!(sample["networks"].is_array());
+}
+
+#[test]
+fn failure_prints_error_object() {
+    let output = litemon(&["--cpu", "false", "--memory", "false"]);
+    assert_eq!(output.status.code(), Some(1));
+    let error = json(&output);
+    assert!(error["error"].is_string());
+    assert_eq!(error.as_object().map(|object| object.len()), Some(1));
+}